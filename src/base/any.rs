@@ -23,7 +23,10 @@ where
       token: item,
       stream,
     },
-    Split::NotEnoughItem(stream) => Parsed::Failure(Context::new(CoreAtom::EndOfStream { stream })),
+    Split::NotEnoughItem(stream) => {
+      let cursor = stream.cursor();
+      Parsed::Failure(Context::new_at(CoreAtom::EndOfStream { stream }, cursor))
+    }
     Split::Error(error) => Parsed::Error(Context::new(CoreAtom::Error { error })),
   }
 }
@@ -55,13 +55,17 @@ macro_rules! base_wrapper {
     where
       (): AsciiParse<Stream, Context>,
     {
+      let cursor = stream.cursor();
       octet
         .try_map(|c| {
           $camel::new(c).ok_or_else(|| {
-            Context::new(BaseAtom::Ascii {
-              found: c,
-              expected: stringify!($pat),
-            })
+            Context::new_at(
+              BaseAtom::Ascii {
+                found: c,
+                expected: stringify!($pat),
+              },
+              cursor,
+            )
           })
         })
         .parse(stream)
@@ -16,6 +16,7 @@ use crate::{
     tag_no_case,
     to_digit,
     BaseAtom,
+    TagItem,
   },
   utils::{
     Acc,
@@ -61,6 +62,7 @@ pub trait FloatParse<Stream, Context> = AsciiParse<Stream, Context>
 where
   Stream: Streaming,
   <Stream as Streaming>::Item: Into<u8>,
+  <Stream as Streaming>::Item: TagItem,
   <Stream as Streaming>::Span: AsRef<[u8]>,
   Context: Contexting<FloatAtom>,
   Context: Contexting<UtilsAtom<Stream>>,
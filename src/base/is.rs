@@ -45,6 +45,7 @@ where
     tracing::instrument(level = "trace", name = "is", skip_all, ret(Display))
   )]
   fn parse(&mut self, stream: Stream) -> Parsed<T, Stream, Context> {
+    let cursor = stream.cursor();
     any
       .map(Into::into)
       .add_atom(|| BaseAtom::Is {
@@ -55,10 +56,13 @@ where
         if self.expect == item {
           Ok(item)
         } else {
-          Err(Context::new(BaseAtom::Is {
-            t: Some(item),
-            expect: self.expect.clone(),
-          }))
+          Err(Context::new_at(
+            BaseAtom::Is {
+              t: Some(item),
+              expect: self.expect.clone(),
+            },
+            cursor,
+          ))
         }
       })
       .parse(stream)
@@ -96,6 +100,7 @@ where
     tracing::instrument(level = "trace", name = "is_not", skip_all, ret(Display))
   )]
   fn parse(&mut self, stream: Stream) -> Parsed<T, Stream, Context> {
+    let cursor = stream.cursor();
     any
       .map(Into::into)
       .add_atom(|| BaseAtom::IsNot {
@@ -106,10 +111,13 @@ where
         if found != self.not_expect {
           Ok(found)
         } else {
-          Err(Context::new(BaseAtom::IsNot {
-            t: Some(found),
-            not_expect: self.not_expect.clone(),
-          }))
+          Err(Context::new_at(
+            BaseAtom::IsNot {
+              t: Some(found),
+              not_expect: self.not_expect.clone(),
+            },
+            cursor,
+          ))
         }
       })
       .parse(stream)
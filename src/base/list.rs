@@ -52,6 +52,7 @@ where
     tracing::instrument(level = "trace", name = "tag", skip_all, ret(Display))
   )]
   fn parse(&mut self, stream: Stream) -> Parsed<&'static [T], Stream, Context> {
+    let cursor = stream.cursor();
     any
       .map(Into::into)
       .try_fold_iter(
@@ -61,10 +62,13 @@ where
           if expect == found {
             Ok(list)
           } else {
-            Err(Context::new(BaseAtom::Is {
-              t: Some(found),
-              expect,
-            }))
+            Err(Context::new_at(
+              BaseAtom::Is {
+                t: Some(found),
+                expect,
+              },
+              cursor,
+            ))
           }
         },
       )
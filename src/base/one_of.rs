@@ -45,6 +45,7 @@ where
     tracing::instrument(level = "trace", name = "one_of", skip_all, ret(Display))
   )]
   fn parse(&mut self, stream: Stream) -> Parsed<T, Stream, Context> {
+    let cursor = stream.cursor();
     any
       .map(Into::into)
       .add_atom(|| BaseAtom::OneOf {
@@ -55,10 +56,13 @@ where
         if self.list.iter().any(|t| t == &item) {
           Ok(item)
         } else {
-          Err(Context::new(BaseAtom::OneOf {
-            found: Some(item),
-            expected: self.list,
-          }))
+          Err(Context::new_at(
+            BaseAtom::OneOf {
+              found: Some(item),
+              expected: self.list,
+            },
+            cursor,
+          ))
         }
       })
       .parse(stream)
@@ -97,6 +101,7 @@ where
     tracing::instrument(level = "trace", name = "none_of", skip_all, ret(Display))
   )]
   fn parse(&mut self, stream: Stream) -> Parsed<T, Stream, Context> {
+    let cursor = stream.cursor();
     any
       .map(Into::into)
       .add_atom(|| BaseAtom::NoneOf {
@@ -107,10 +112,13 @@ where
         if self.list.iter().all(|i| i != &t) {
           Ok(t)
         } else {
-          Err(Context::new(BaseAtom::NoneOf {
-            found: Some(t),
-            not_expected: self.list,
-          }))?
+          Err(Context::new_at(
+            BaseAtom::NoneOf {
+              found: Some(t),
+              not_expected: self.list,
+            },
+            cursor,
+          ))?
         }
       })
       .parse(stream)
@@ -20,6 +20,21 @@ pub trait NumberParse<Stream, Context> = where
   Context: Contexting<UtilsAtom<Stream>>,
   Context: Contexting<CoreAtom<Stream>>;
 
+// A stream that doesn't have all its data yet, like a partial stream, is asked
+// for all octet at once first so it report exactly how many are missing.
+fn primitive<Stream, Context, T, const N: usize>(
+  stream: Stream, f: fn([u8; N]) -> T,
+) -> Parsed<T, Stream, Context>
+where
+  (): NumberParse<Stream, Context>,
+{
+  if let Err(error) = stream.require(N) {
+    return Parsed::Error(Context::new(CoreAtom::Error { error }));
+  }
+
+  octet.fill().map(f).parse(stream)
+}
+
 macro_rules! impl_primitive {
   ($primitive:ident) => {
     paste! {
@@ -34,7 +49,7 @@ macro_rules! impl_primitive {
       where
         (): NumberParse<Stream, Context>,
       {
-        octet.fill().map($primitive::from_be_bytes).parse(stream)
+        primitive(stream, $primitive::from_be_bytes)
       }
 
       /// Parse binary $primitive in little endian
@@ -48,7 +63,7 @@ macro_rules! impl_primitive {
       where
         (): NumberParse<Stream, Context>,
       {
-        octet.fill().map($primitive::from_le_bytes).parse(stream)
+        primitive(stream, $primitive::from_le_bytes)
       }
 
       /// Parse binary $primitive in native endian
@@ -62,7 +77,7 @@ macro_rules! impl_primitive {
       where
         (): NumberParse<Stream, Context>,
       {
-        octet.fill().map($primitive::from_ne_bytes).parse(stream)
+        primitive(stream, $primitive::from_ne_bytes)
       }
     }
   };
@@ -14,12 +14,33 @@ use crate::{
   Streaming,
 };
 
+/// Item of a Stream that can be compared with a tag, give the number of Item
+/// needed to hold the tag. By default one Item is one octet of the tag, so
+/// an Item that hold octet only need `impl TagItem for Item {}`.
+pub trait TagItem {
+  /// Return the number of Item of tag
+  fn tag_len(tag: &str) -> usize {
+    tag.len()
+  }
+}
+
+/// Stream of octet, the number of octet of tag
+impl TagItem for u8 {}
+
+/// Stream of char like `&str`, the number of char of tag
+impl TagItem for char {
+  fn tag_len(tag: &str) -> usize {
+    tag.chars().count()
+  }
+}
+
 /// Take a &'static str and return a Parser that will
 /// compare it with Stream, this requiere the Stream Span
 /// to implement `AsRef<[u8]>`
 pub fn tag<Stream, Context>(tag: &'static str) -> impl Parse<Stream, Context, Token = &'static str>
 where
   Stream: Streaming,
+  Stream::Item: TagItem,
   Stream::Span: AsRef<[u8]>,
   Context: Contexting<CoreAtom<Stream>>,
   Context: Contexting<BaseAtom<u8>>,
@@ -35,6 +56,7 @@ struct Tag {
 impl<Stream, Context> Parse<Stream, Context> for Tag
 where
   Stream: Streaming,
+  Stream::Item: TagItem,
   Stream::Span: AsRef<[u8]>,
   Context: Contexting<CoreAtom<Stream>>,
   Context: Contexting<BaseAtom<u8>>,
@@ -47,16 +69,7 @@ where
     tracing::instrument(level = "trace", name = "tag", skip_all, ret(Display))
   )]
   fn parse(&mut self, stream: Stream) -> Parsed<&'static str, Stream, Context> {
-    take(self.tag.len())
-      .add_atom(|| BaseAtom::Tag { tag: self.tag })
-      .try_map(|token: Stream::Span| {
-        if token.as_ref() == self.tag.as_bytes() {
-          Ok(self.tag)
-        } else {
-          Err(Context::new(BaseAtom::Tag { tag: self.tag }))
-        }
-      })
-      .parse(stream)
+    take_tag(self.tag, <[u8]>::eq, stream)
   }
 }
 
@@ -68,6 +81,7 @@ pub fn tag_no_case<Stream, Context>(
 ) -> impl Parse<Stream, Context, Token = &'static str>
 where
   Stream: Streaming,
+  Stream::Item: TagItem,
   Stream::Span: AsRef<[u8]>,
   Context: Contexting<CoreAtom<Stream>>,
   Context: Contexting<BaseAtom<u8>>,
@@ -83,6 +97,7 @@ struct TagNoCase {
 impl<Stream, Context> Parse<Stream, Context> for TagNoCase
 where
   Stream: Streaming,
+  Stream::Item: TagItem,
   Stream::Span: AsRef<[u8]>,
   Context: Contexting<CoreAtom<Stream>>,
   Context: Contexting<BaseAtom<u8>>,
@@ -95,15 +110,30 @@ where
     tracing::instrument(level = "trace", name = "tag_no_case", skip_all, ret(Display))
   )]
   fn parse(&mut self, stream: Stream) -> Parsed<&'static str, Stream, Context> {
-    take(self.tag.len())
-      .add_atom(|| BaseAtom::Tag { tag: self.tag })
-      .try_map(|token: Stream::Span| {
-        if token.as_ref().eq_ignore_ascii_case(self.tag.as_bytes()) {
-          Ok(self.tag)
-        } else {
-          Err(Context::new(BaseAtom::Tag { tag: self.tag }))
-        }
-      })
-      .parse(stream)
+    take_tag(self.tag, <[u8]>::eq_ignore_ascii_case, stream)
   }
 }
+
+fn take_tag<Stream, Context>(
+  tag: &'static str, eq: fn(&[u8], &[u8]) -> bool, stream: Stream,
+) -> Parsed<&'static str, Stream, Context>
+where
+  Stream: Streaming,
+  Stream::Item: TagItem,
+  Stream::Span: AsRef<[u8]>,
+  Context: Contexting<CoreAtom<Stream>>,
+  Context: Contexting<BaseAtom<u8>>,
+  Context: Contexting<UtilsAtom<Stream>>,
+{
+  let cursor = stream.cursor();
+  take(Stream::Item::tag_len(tag))
+    .add_atom(|| BaseAtom::Tag { tag })
+    .try_map(move |token: Stream::Span| {
+      if eq(token.as_ref(), tag.as_bytes()) {
+        Ok(tag)
+      } else {
+        Err(Context::new_at(BaseAtom::Tag { tag }, cursor))
+      }
+    })
+    .parse(stream)
+}
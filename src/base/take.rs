@@ -39,7 +39,8 @@ where
         stream,
       },
       Split::NotEnoughItem(stream) => {
-        Parsed::Failure(Context::new(CoreAtom::EndOfStream { stream }))
+        let cursor = stream.cursor();
+        Parsed::Failure(Context::new_at(CoreAtom::EndOfStream { stream }, cursor))
       }
       Split::Error(error) => Parsed::Error(Context::new(CoreAtom::Error { error })),
     }
@@ -10,8 +10,10 @@ use core::{
 };
 
 use crate::{
+  context::ProvidePosition,
   Contexting,
   ProvideElement,
+  ProvideElements,
 };
 
 /// A Context container that ignore all Atom
@@ -53,3 +55,18 @@ impl ProvideElement for Ignore {
     &Ignore
   }
 }
+
+impl ProvideElements for Ignore {
+  fn try_for_each_element<E, F>(&self, mut f: F) -> Result<(), E>
+  where
+    F: FnMut(&Self::Element) -> Result<(), E>,
+  {
+    f(&Ignore)
+  }
+}
+
+impl ProvidePosition for Ignore {
+  fn position(&self) -> Option<usize> {
+    None
+  }
+}
@@ -11,9 +11,11 @@ use crate::{
   context::{
     First,
     Last,
+    ProvidePosition,
   },
   Contexting,
   ProvideElement,
+  ProvideElements,
 };
 
 /// Will keep only the first or the last Element that was feed to it.
@@ -43,6 +45,22 @@ impl<Behavior, Element: Display> ProvideElement for Keep<Behavior, Element> {
   }
 }
 
+impl<Behavior, Element: Display> ProvideElements for Keep<Behavior, Element> {
+  fn try_for_each_element<E, F>(&self, mut f: F) -> Result<(), E>
+  where
+    F: FnMut(&Self::Element) -> Result<(), E>,
+  {
+    f(&self.element)
+  }
+}
+
+/// Keep doesn't know where the failure happened
+impl<Behavior, Element> ProvidePosition for Keep<Behavior, Element> {
+  fn position(&self) -> Option<usize> {
+    None
+  }
+}
+
 impl<Element, Atom: Into<Element>> Add<Atom> for Keep<First, Element> {
   type Output = Self;
 
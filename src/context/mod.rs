@@ -13,6 +13,8 @@ mod tree;
 #[cfg(feature = "tree")]
 pub use tree::*;
 
+use core::ops::Range;
+
 /// Used to determine by Keep and Stack to determine their Behavior
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct First;
@@ -20,3 +22,29 @@ pub struct First;
 /// Used to determine by Keep and Stack to determine their Behavior
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Last;
+
+/// Used by Stack to keep the elements of the failure that went the furthest
+/// in the stream. The position is the cursor of the stream given by parser
+/// when they build the Context, see [crate::Contexting::new_at].
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Furthest {
+  position: Option<usize>,
+  // smallest cursor of the parsers that added an Atom to the failure
+  start: Option<usize>,
+  // number of elements at the start of the Stack produced at position
+  expected: usize,
+}
+
+/// Context that know the position in the stream where the failure happened
+pub trait ProvidePosition {
+  /// Return the cursor of the stream where the failure happened, see
+  /// [crate::Streaming::cursor], None if it's unknown
+  fn position(&self) -> Option<usize>;
+
+  /// Return the cursors of the span that failed, it start where the parser
+  /// that failed started and end at [ProvidePosition::position]. By default
+  /// the span is empty.
+  fn span(&self) -> Option<Range<usize>> {
+    self.position().map(|position| position..position)
+  }
+}
@@ -1,9 +1,10 @@
 use core::{
+  cmp::Ordering,
   fmt::Display,
-  marker::PhantomData,
   ops::{
     Add,
     BitOr,
+    Range,
   },
 };
 
@@ -15,27 +16,39 @@ use smallvec::{
 use crate::{
   context::{
     First,
+    Furthest,
     Last,
+    ProvidePosition,
   },
   Contexting,
   ProvideElement,
+  ProvideElements,
 };
 
 /// Will keep the last Stack of elements feed to it.
 #[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Stack<Behavior, Context, const N: usize> {
-  behavior: PhantomData<Behavior>,
+  behavior: Behavior,
   backtrace: SmallVec<[Context; N]>,
 }
 
-impl<Behavior, Context: Display, Atom: Into<Context>, const N: usize> Contexting<Atom>
-  for Stack<Behavior, Context, N>
-where
-  Self: Add<Atom, Output = Self> + BitOr<Output = Self>,
+impl<Context: Display, Atom: Into<Context>, const N: usize> Contexting<Atom>
+  for Stack<First, Context, N>
+{
+  fn new(atom: Atom) -> Self {
+    Self {
+      behavior: First,
+      backtrace: smallvec![atom.into()],
+    }
+  }
+}
+
+impl<Context: Display, Atom: Into<Context>, const N: usize> Contexting<Atom>
+  for Stack<Last, Context, N>
 {
   fn new(atom: Atom) -> Self {
     Self {
-      behavior: PhantomData::default(),
+      behavior: Last,
       backtrace: smallvec![atom.into()],
     }
   }
@@ -50,6 +63,15 @@ impl<Behavior, Element: Display, const N: usize> ProvideElement for Stack<Behavi
   }
 }
 
+impl<Behavior, Element: Display, const N: usize> ProvideElements for Stack<Behavior, Element, N> {
+  fn try_for_each_element<E, F>(&self, f: F) -> Result<(), E>
+  where
+    F: FnMut(&Self::Element) -> Result<(), E>,
+  {
+    self.backtrace.iter().try_for_each(f)
+  }
+}
+
 impl<Context, Atom: Into<Context>, const N: usize> Add<Atom> for Stack<First, Context, N> {
   type Output = Self;
 
@@ -67,6 +89,13 @@ impl<Context, const N: usize> BitOr for Stack<First, Context, N> {
   }
 }
 
+/// Only Furthest know where the failure happened
+impl<Element, const N: usize> ProvidePosition for Stack<First, Element, N> {
+  fn position(&self) -> Option<usize> {
+    None
+  }
+}
+
 impl<Context, Atom: Into<Context>, const N: usize> Add<Atom> for Stack<Last, Context, N> {
   type Output = Self;
 
@@ -83,3 +112,197 @@ impl<Context, const N: usize> BitOr for Stack<Last, Context, N> {
     other
   }
 }
+
+/// Only Furthest know where the failure happened
+impl<Element, const N: usize> ProvidePosition for Stack<Last, Element, N> {
+  fn position(&self) -> Option<usize> {
+    None
+  }
+}
+
+impl<Element, const N: usize> Stack<Furthest, Element, N> {
+  /// Return the expected elements of all failures that stopped at the
+  /// furthest position, "expected one of" them
+  pub fn expected(&self) -> impl Iterator<Item = &Element> {
+    self.backtrace[..self.behavior.expected].iter()
+  }
+}
+
+impl<Element, const N: usize> ProvidePosition for Stack<Furthest, Element, N> {
+  fn position(&self) -> Option<usize> {
+    self.behavior.position
+  }
+
+  fn span(&self) -> Option<Range<usize>> {
+    let end = self.behavior.position?;
+    Some(self.behavior.start.unwrap_or(end)..end)
+  }
+}
+
+// smallest known cursor
+fn min_cursor(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+  match (a, b) {
+    (Some(a), Some(b)) => Some(a.min(b)),
+    (a, b) => a.or(b),
+  }
+}
+
+impl<Element, Atom, const N: usize> Contexting<Atom> for Stack<Furthest, Element, N>
+where
+  Element: Display + PartialEq,
+  Atom: Into<Element>,
+{
+  fn new(atom: Atom) -> Self {
+    Self::new_at(atom, None)
+  }
+
+  fn new_at(atom: Atom, cursor: Option<usize>) -> Self {
+    Self {
+      behavior: Furthest {
+        position: cursor,
+        start: cursor,
+        expected: 1,
+      },
+      backtrace: smallvec![atom.into()],
+    }
+  }
+
+  // an Atom added by a parser called where the failure happened is expected
+  // too, like the expectation of `is` when the stream is empty, others
+  // extend the span of the failure to where their parser started
+  fn add_at(mut self, atom: Atom, cursor: Option<usize>) -> Self {
+    if cursor.is_some() && cursor == self.behavior.position {
+      self.backtrace.insert(self.behavior.expected, atom.into());
+      self.behavior.expected += 1;
+      self
+    } else {
+      if cursor < self.behavior.position {
+        self.behavior.start = min_cursor(self.behavior.start, cursor);
+      }
+      self.add(atom)
+    }
+  }
+}
+
+impl<Context, Atom: Into<Context>, const N: usize> Add<Atom> for Stack<Furthest, Context, N> {
+  type Output = Self;
+
+  fn add(mut self, context: Atom) -> Self {
+    self.backtrace.push(context.into());
+    self
+  }
+}
+
+impl<Element, const N: usize> BitOr for Stack<Furthest, Element, N>
+where
+  Element: PartialEq,
+{
+  type Output = Self;
+
+  fn bitor(mut self, other: Self) -> Self {
+    match self.behavior.position.cmp(&other.behavior.position) {
+      Ordering::Greater => self,
+      Ordering::Less => other,
+      Ordering::Equal => {
+        self.behavior.start = min_cursor(self.behavior.start, other.behavior.start);
+        // merge expected elements without duplicate
+        let expected = other.behavior.expected;
+        for element in other.backtrace.into_iter().take(expected) {
+          if !self.backtrace[..self.behavior.expected].contains(&element) {
+            self.backtrace.insert(self.behavior.expected, element);
+            self.behavior.expected += 1;
+          }
+        }
+        self
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use super::Stack;
+  use crate::{
+    base::{
+      is,
+      BaseAtom,
+    },
+    context::{
+      Furthest,
+      ProvidePosition,
+    },
+    utils::Utils,
+    CoreAtom,
+    Parse,
+    Parsed,
+    Streaming,
+  };
+
+  type Stream = &'static [u8];
+
+  #[derive(Display, Debug, From, PartialEq)]
+  enum FromAtom {
+    Base(BaseAtom<u8>),
+    Core(CoreAtom<Stream>),
+  }
+
+  type HandleAtom = Stack<Furthest, FromAtom, 4>;
+
+  #[test]
+  fn furthest() {
+    let stream = &b"abx"[..];
+    let parsed: Parsed<_, _, HandleAtom> = is(b'a')
+      .drop_and(is(b'c'))
+      .or(is(b'a').and(is(b'b')).drop_and(is(b'y')))
+      .or(is(b'x'))
+      .or(is(b'a').and(is(b'b')).drop_and(is(b'z')))
+      .parse(stream);
+    let Parsed::Failure(context) = parsed else {
+      panic!()
+    };
+    assert_eq!(context.position(), (&stream[2..]).cursor());
+    let expected = [
+      FromAtom::Base(BaseAtom::Is {
+        t: Some(b'x'),
+        expect: b'y',
+      }),
+      FromAtom::Base(BaseAtom::Is {
+        t: Some(b'x'),
+        expect: b'z',
+      }),
+    ];
+    assert!(context.expected().eq(&expected));
+  }
+
+  #[test]
+  fn end_of_stream() {
+    let stream = &b"ab"[..];
+    let parsed: Parsed<_, _, HandleAtom> = is(b'a')
+      .drop_and(is(b'c'))
+      .or(is(b'a').and(is(b'b')).drop_and(is(b'c')))
+      .or(is(b'a').and(is(b'b')).drop_and(is(b'd')))
+      .parse(stream);
+    let Parsed::Failure(context) = parsed else {
+      panic!()
+    };
+    let end = &stream[2..];
+    assert_eq!(context.position(), end.cursor());
+    let expected = [
+      FromAtom::Core(CoreAtom::EndOfStream { stream: end }),
+      FromAtom::Base(BaseAtom::Is {
+        t: None,
+        expect: b'c',
+      }),
+      FromAtom::Base(BaseAtom::Is {
+        t: None,
+        expect: b'd',
+      }),
+    ];
+    assert!(context.expected().eq(&expected));
+  }
+}
@@ -10,9 +10,11 @@ use core::{
 };
 
 use crate::{
+  context::ProvidePosition,
   utils::Acc,
   Contexting,
   ProvideElement,
+  ProvideElements,
 };
 
 /// Will keep the full tree of elements feed to it.
@@ -35,6 +37,16 @@ impl<Context> Tree<Context> {
     }
   }
 
+  fn try_for_each<E, F>(&self, f: &mut F) -> Result<(), E>
+  where
+    F: FnMut(&Context) -> Result<(), E>,
+  {
+    match self {
+      Tree::Element(context) => f(context),
+      Tree::And(tree) | Tree::Or(tree) => tree.iter().try_for_each(|tree| tree.try_for_each(f)),
+    }
+  }
+
   fn unwrap_last_or_context(or: &[Self]) -> &Context {
     match or.last().unwrap() {
       Tree::Element(context) => context,
@@ -62,6 +74,22 @@ impl<Element: Display> ProvideElement for Tree<Element> {
   }
 }
 
+impl<Element: Display> ProvideElements for Tree<Element> {
+  fn try_for_each_element<E, F>(&self, mut f: F) -> Result<(), E>
+  where
+    F: FnMut(&Element) -> Result<(), E>,
+  {
+    self.try_for_each(&mut f)
+  }
+}
+
+/// Tree doesn't know where the failure happened
+impl<Element> ProvidePosition for Tree<Element> {
+  fn position(&self) -> Option<usize> {
+    None
+  }
+}
+
 impl<Context, Atom: Into<Context>> Add<Atom> for Tree<Context> {
   type Output = Self;
 
@@ -15,6 +15,18 @@ pub trait Contexting<Atom>:
   /// Create a new Context from a Atom.
   /// Implementation should try to avoid allocate for only one Atom.
   fn new(atom: Atom) -> Self;
+
+  /// Same than [Contexting::new] but the Atom was produced at cursor of the
+  /// stream, see [crate::Streaming::cursor]. By default cursor is ignored.
+  fn new_at(atom: Atom, _cursor: Option<usize>) -> Self {
+    Self::new(atom)
+  }
+
+  /// Same than Add but the Atom was produced by a parser called at cursor of
+  /// the stream. By default cursor is ignored.
+  fn add_at(self, atom: Atom, _cursor: Option<usize>) -> Self {
+    self.add(atom)
+  }
 }
 
 /// This is an utily trait
@@ -26,3 +38,12 @@ pub trait ProvideElement {
   /// return the last Element added to a Context
   fn last(&self) -> &Self::Element;
 }
+
+/// This is an utily trait to access all elements of a Context
+pub trait ProvideElements: ProvideElement {
+  /// Call f with every Element of the Context from the first added to the
+  /// last, stop at the first Err returned by f.
+  fn try_for_each_element<E, F>(&self, f: F) -> Result<(), E>
+  where
+    F: FnMut(&Self::Element) -> Result<(), E>;
+}
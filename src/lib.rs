@@ -35,6 +35,8 @@ mod parsed_aux;
 pub use parsed_aux::*;
 mod parsed;
 pub use parsed::*;
+mod report;
+pub use report::*;
 
 mod streaming;
 pub use streaming::*;
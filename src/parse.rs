@@ -76,7 +76,7 @@ where
                 acc = acc.bitor(context);
               }
               Parsed::Error(context) => {
-                return Parsed::Error(acc.bitor(context));
+                return Parsed::Error(context);
               }
             }
           }
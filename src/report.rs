@@ -0,0 +1,349 @@
+use core::{
+  fmt::{
+    self,
+    Display,
+    Formatter,
+  },
+  iter,
+  ops::Range,
+};
+
+use owo_colors::{
+  OwoColorize,
+  Style,
+};
+
+use crate::{
+  context::ProvidePosition,
+  Parsed,
+  ProvideElements,
+  Streaming,
+};
+
+/// Render a Failure or an Error against the input that was parsed. The line
+/// where the parser stopped is printed with the span of the failure
+/// underlined and all the elements of the context are listed as notes. The
+/// span is the one provided by the context, see [ProvidePosition], it's
+/// compared to the cursor of input so input must be the stream given to the
+/// parser or the stream it wrap, like the input of a [crate::stream::Located],
+/// see [Streaming::cursor]. Octets that are not utf8 are printed escaped.
+#[derive(Debug)]
+pub struct Report<'a, Context> {
+  input: &'a [u8],
+  cursor: Option<usize>,
+  context: &'a Context,
+  error: bool,
+  color: bool,
+}
+
+impl<'a, Context> Clone for Report<'a, Context> {
+  fn clone(&self) -> Self {
+    *self
+  }
+}
+
+impl<'a, Context> Copy for Report<'a, Context> {}
+
+impl<'a, Context> Report<'a, Context> {
+  /// Return a new Report of parsed, None if parsed is a Success
+  pub fn new<Token, Stream, Input>(
+    input: &'a Input, parsed: &'a Parsed<Token, Stream, Context>,
+  ) -> Option<Self>
+  where
+    Input: Streaming + AsRef<[u8]>,
+  {
+    let (context, error) = match parsed {
+      Parsed::Success { .. } => return None,
+      Parsed::Failure(context) => (context, false),
+      Parsed::Error(context) => (context, true),
+    };
+
+    Some(Self {
+      input: input.as_ref(),
+      cursor: input.cursor(),
+      context,
+      error,
+      color: false,
+    })
+  }
+
+  /// Enable or disable color, default is without color
+  pub const fn color(self, color: bool) -> Self {
+    Self { color, ..self }
+  }
+
+  fn style(&self, style: Style) -> Style {
+    if self.color {
+      style
+    } else {
+      Style::new()
+    }
+  }
+}
+
+impl<'a, Context> Report<'a, Context>
+where
+  Context: ProvideElements + ProvidePosition,
+{
+  // span in octet from the start of input
+  fn span(&self) -> Option<Range<usize>> {
+    let span = self.context.span()?;
+    let cursor = self.cursor?;
+    let end = span.end.checked_sub(cursor)?;
+    if end > self.input.len() {
+      return None;
+    }
+    let start = span.start.saturating_sub(cursor).min(end);
+    Some(start..end)
+  }
+}
+
+// number of char used by Text to write an octet that is not utf8, `\xNN`
+const ESCAPED: usize = 4;
+
+// write octets as text, escape the ones that are not utf8 in ESCAPED char
+struct Text<'a>(&'a [u8]);
+
+impl<'a> Display for Text<'a> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    for chunk in self.0.utf8_chunks() {
+      write!(f, "{}", chunk.valid())?;
+      for octet in chunk.invalid() {
+        write!(f, "\\x{:02x}", octet)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+// number of char, an octet that is not utf8 count as one
+fn chars(octets: &[u8]) -> usize {
+  octets
+    .utf8_chunks()
+    .map(|chunk| chunk.valid().chars().count() + chunk.invalid().len())
+    .sum()
+}
+
+// write fill under every char of Text, keep tab to stay aligned, ESCAPED
+// fill for an octet that is not utf8
+struct Under<'a> {
+  text: &'a [u8],
+  fill: char,
+}
+
+impl<'a> Display for Under<'a> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    for chunk in self.text.utf8_chunks() {
+      for c in chunk.valid().chars() {
+        write!(f, "{}", if c == '\t' { '\t' } else { self.fill })?;
+      }
+      for _ in 0..chunk.invalid().len() * ESCAPED {
+        write!(f, "{}", self.fill)?;
+      }
+    }
+    Ok(())
+  }
+}
+
+impl<'a, Context> Display for Report<'a, Context>
+where
+  Context: ProvideElements + ProvidePosition,
+{
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    let (kind, style) = if self.error {
+      ("error", Style::new().red().bold())
+    } else {
+      ("failure", Style::new().yellow().bold())
+    };
+    let style = self.style(style);
+    let gutter = self.style(Style::new().blue().bold());
+    writeln!(f, "{}: {}", kind.style(style), self.context.last())?;
+
+    let mut width = 0;
+    if let Some(Range { start, end: offset }) = self.span() {
+      let input = self.input;
+      let begin = input[..offset]
+        .iter()
+        .rposition(|&o| o == b'\n')
+        .map_or(0, |i| i + 1);
+      let end = input[offset..]
+        .iter()
+        .position(|&o| o == b'\n')
+        .map_or(input.len(), |i| offset + i);
+      // the span can start on a previous line
+      let start = start.max(begin);
+      let text = &input[begin..end];
+      let line = input[..begin].iter().filter(|&&o| o == b'\n').count() + 1;
+      let column = chars(&input[begin..offset]) + 1;
+      width = iter::successors(Some(line), |&n| (n >= 10).then_some(n / 10)).count();
+
+      writeln!(
+        f,
+        "{:width$}{} {}:{}",
+        "",
+        "-->".style(gutter),
+        line,
+        column
+      )?;
+      writeln!(f, "{:width$} {}", "", "|".style(gutter))?;
+      writeln!(
+        f,
+        "{} {} {}",
+        line.style(gutter),
+        "|".style(gutter),
+        Text(text.strip_suffix(b"\r").unwrap_or(text))
+      )?;
+      writeln!(
+        f,
+        "{:width$} {} {}{}{}",
+        "",
+        "|".style(gutter),
+        Under {
+          text: &input[begin..start],
+          fill: ' ',
+        },
+        Under {
+          text: &input[start..offset],
+          fill: '^',
+        }
+        .style(style),
+        "^".style(style)
+      )?;
+    }
+
+    self.context.try_for_each_element(|element| {
+      writeln!(f, "{:width$} {} note: {}", "", "=".style(gutter), element)
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::string::ToString;
+
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use super::Report;
+  use crate::{
+    base::{
+      is,
+      BaseAtom,
+    },
+    context::{
+      Furthest,
+      Ignore,
+      Stack,
+    },
+    utils::Utils,
+    CoreAtom,
+    Parse,
+    Parsed,
+  };
+
+  #[derive(Display, Debug, PartialEq)]
+  #[display(fmt = "in a pair")]
+  struct Pair;
+
+  #[derive(Display, Debug, From, PartialEq)]
+  enum TextAtom {
+    Base(BaseAtom<char>),
+    Core(CoreAtom<&'static str>),
+    Pair(Pair),
+  }
+
+  #[derive(Display, Debug, From, PartialEq)]
+  enum OctetAtom {
+    Base(BaseAtom<u8>),
+    Core(CoreAtom<&'static [u8]>),
+    Pair(Pair),
+  }
+
+  #[test]
+  fn report() {
+    let input = "ab\n\tcd\n";
+    let parsed: Parsed<_, _, Stack<Furthest, TextAtom, 4>> = is('a')
+      .and(is('b'))
+      .and(is('\n'))
+      .and(is('\t'))
+      .and(is('c').and(is('x').or(is('y'))).add_atom(|| Pair))
+      .parse(input);
+    let report = Report::new(&input, &parsed).unwrap();
+    assert_eq!(
+      report.to_string(),
+      "failure: in a pair
+ --> 2:3
+  |
+2 | \tcd
+  | \t^^
+  = note: Is: Some('d') != x
+  = note: Is: Some('d') != y
+  = note: in a pair
+"
+    );
+    assert_ne!(report.color(true).to_string(), report.to_string());
+  }
+
+  #[test]
+  fn octets() {
+    let input = &b"ok\xffz"[..];
+    let parsed: Parsed<_, _, Stack<Furthest, OctetAtom, 4>> =
+      is(b'o').and(is(b'k')).and(is(b'y')).parse(input);
+    let report = Report::new(&input, &parsed).unwrap();
+    assert_eq!(
+      report.to_string(),
+      "failure: Is: Some(255) != 121
+ --> 1:3
+  |
+1 | ok\\xffz
+  |   ^
+  = note: Is: Some(255) != 121
+"
+    );
+
+    // the span cover the escaped octet
+    let parsed: Parsed<_, _, Stack<Furthest, OctetAtom, 4>> = is(b'o')
+      .and(is(b'k').and(is(0xFF)).and(is(b'y')).add_atom(|| Pair))
+      .parse(input);
+    let report = Report::new(&input, &parsed).unwrap();
+    assert_eq!(
+      report.to_string(),
+      "failure: in a pair
+ --> 1:4
+  |
+1 | ok\\xffz
+  |  ^^^^^^
+  = note: Is: Some(122) != 121
+  = note: in a pair
+"
+    );
+  }
+
+  #[cfg(feature = "tree")]
+  #[test]
+  fn tree() {
+    use crate::context::Tree;
+
+    let input = "c";
+    let parsed: Parsed<_, _, Tree<TextAtom>> = is('a').or(is('b')).parse(input);
+    let report = Report::new(&input, &parsed).unwrap();
+    assert_eq!(
+      report.to_string(),
+      "failure: Is: Some('c') != b
+ = note: Is: Some('c') != a
+ = note: Is: Some('c') != b
+"
+    );
+  }
+
+  #[test]
+  fn without_position() {
+    let parsed: Parsed<(), &str, Ignore> = Parsed::Error(Ignore);
+    let report = Report::new(&"", &parsed).unwrap();
+    assert_eq!(report.to_string(), "error: Unknown\n = note: Unknown\n");
+    let parsed: Parsed<(), &str, Ignore> = Parsed::new_success((), "");
+    assert!(Report::new(&"", &parsed).is_none());
+  }
+}
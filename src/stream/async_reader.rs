@@ -0,0 +1,375 @@
+use alloc::rc::Rc;
+use core::{
+  cell::Cell,
+  convert::Infallible,
+};
+use std::io::{
+  self,
+  ErrorKind,
+};
+
+use futures::io::{
+  AsyncRead,
+  AsyncReadExt,
+};
+
+use crate::{
+  stream::{
+    Partial,
+    PartialError,
+    VecStream,
+  },
+  Parse,
+  Parsed,
+  Split,
+  StreamPosition,
+  Streaming,
+  Success,
+};
+
+/// Stream given to parser by [AsyncReader::parse], a [Partial] view of the
+/// buffered data that remember how many octet was missing the last time it
+/// was incomplete. The data are shared with the [AsyncReader], not copied.
+#[derive(Clone, Debug)]
+pub struct AsyncStream {
+  stream: Partial<VecStream>,
+  needed: Rc<Cell<Option<usize>>>,
+}
+
+impl PartialEq for AsyncStream {
+  fn eq(&self, other: &Self) -> bool {
+    self.stream == other.stream
+  }
+}
+
+impl Eq for AsyncStream {}
+
+impl AsyncStream {
+  /// Return the inner Partial stream
+  pub fn into_inner(self) -> Partial<VecStream> {
+    self.stream
+  }
+
+  fn with_stream(&self, stream: Partial<VecStream>) -> Self {
+    Self {
+      stream,
+      needed: Rc::clone(&self.needed),
+    }
+  }
+
+  fn incomplete(&self, error: &PartialError<Infallible>) {
+    if let PartialError::Incomplete { needed } = error {
+      // when the number is unknown at least one more octet is needed
+      self.needed.set(Some(needed.unwrap_or(1)));
+    }
+  }
+
+  fn split<Item>(
+    &self, split: Split<Item, Partial<VecStream>, PartialError<Infallible>>,
+  ) -> Split<Item, Self, PartialError<Infallible>> {
+    match split {
+      Split::Success { item, stream } => Split::Success {
+        item,
+        stream: self.with_stream(stream),
+      },
+      Split::NotEnoughItem(stream) => Split::NotEnoughItem(self.with_stream(stream)),
+      Split::Error(error) => {
+        self.incomplete(&error);
+        Split::Error(error)
+      }
+    }
+  }
+}
+
+impl Streaming for AsyncStream {
+  type Error = PartialError<Infallible>;
+  type Item = u8;
+  type Span = VecStream;
+
+  fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
+    self.split(self.stream.clone().split_first())
+  }
+
+  fn split_at(self, mid: usize) -> Split<Self::Span, Self, Self::Error> {
+    self.split(self.stream.clone().split_at(mid))
+  }
+
+  fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
+    self.split(self.stream.clone().split_last())
+  }
+
+  fn all(self) -> Result<Success<Self::Span, Self>, Self::Error> {
+    match self.stream.clone().all() {
+      Ok(Success { token, stream }) => Ok(Success {
+        token,
+        stream: self.with_stream(stream),
+      }),
+      Err(error) => {
+        self.incomplete(&error);
+        Err(error)
+      }
+    }
+  }
+
+  fn diff(self, other: &Self) -> Result<Self::Span, Self> {
+    match self.stream.clone().diff(&other.stream) {
+      Ok(span) => Ok(span),
+      Err(_) => Err(self),
+    }
+  }
+
+  fn require(&self, n: usize) -> Result<(), Self::Error> {
+    self
+      .stream
+      .require(n)
+      .inspect_err(|error| self.incomplete(error))
+  }
+
+  fn cursor(&self) -> Option<usize> {
+    self.stream.cursor()
+  }
+}
+
+/// Offset is the offset in the buffer of the [AsyncReader]
+impl StreamPosition for AsyncStream {
+  fn offset(&self) -> usize {
+    self.stream.offset()
+  }
+}
+
+/// Buffer data from an `AsyncRead` and drive parser on them.
+/// Parser are called on an [AsyncStream], when a parser need more data the
+/// number of octet missing is read from the reader and the parser is called
+/// again, `N` is the number of octet asked to the reader for each read.
+/// Data used by a successful parser are removed from the buffer so the same
+/// parser can be called again to decode the next frame.
+#[derive(Debug)]
+pub struct AsyncReader<Reader, const N: usize> {
+  reader: Reader,
+  buf: Rc<Vec<u8>>,
+  // octet used by parser, removed at the next read
+  used: usize,
+  eof: bool,
+}
+
+impl<Reader, const N: usize> AsyncReader<Reader, N>
+where
+  Reader: AsyncRead + Unpin,
+{
+  /// Return a new AsyncReader from an `AsyncRead`
+  pub fn new(reader: Reader) -> Self {
+    Self {
+      reader,
+      buf: Rc::new(Vec::new()),
+      used: 0,
+      eof: false,
+    }
+  }
+
+  /// Return true if the reader reached end of file
+  pub const fn is_eof(&self) -> bool {
+    self.eof
+  }
+
+  /// Return the data buffered not yet used by a parser
+  pub fn buffer(&self) -> &[u8] {
+    &self.buf[self.used..]
+  }
+
+  /// Return the reader, data buffered are lost
+  pub fn into_inner(self) -> Reader {
+    self.reader
+  }
+
+  /// Return a stream of the buffered data, the stream is complete if the
+  /// reader reached end of file
+  pub fn stream(&self) -> AsyncStream {
+    let stream = Partial::new(VecStream::from_rc(
+      Rc::clone(&self.buf),
+      self.used..self.buf.len(),
+    ));
+    AsyncStream {
+      stream: if self.eof { stream.complete() } else { stream },
+      needed: Rc::new(Cell::new(None)),
+    }
+  }
+
+  /// Read at most N octet from the reader into the buffer, return the number
+  /// of octet read, 0 mean end of file
+  pub async fn read(&mut self) -> Result<usize, io::Error> {
+    // the buffer is only copied if a stream still use it
+    let buf = Rc::make_mut(&mut self.buf);
+    buf.drain(..self.used);
+    self.used = 0;
+
+    let len = buf.len();
+    buf.resize(len + N, 0);
+    let n = loop {
+      match self.reader.read(&mut buf[len..]).await {
+        Ok(n) => break n,
+        Err(error) if error.kind() == ErrorKind::Interrupted => {}
+        Err(error) => {
+          buf.truncate(len);
+          return Err(error);
+        }
+      }
+    };
+    buf.truncate(len + n);
+    self.eof = n == 0;
+    Ok(n)
+  }
+
+  // read until n more octet are buffered or end of file
+  async fn fill(&mut self, n: usize) -> Result<(), io::Error> {
+    let mut read = 0;
+    while read < n {
+      match self.read().await? {
+        0 => break,
+        n => read += n,
+      }
+    }
+    Ok(())
+  }
+
+  /// Call parser on the buffered data until it doesn't need more data. If
+  /// parser return an Error because the stream was incomplete and the reader
+  /// didn't reach end of file, the octet missing are read and parser is
+  /// called again. If parser is successful the data used are removed from
+  /// the buffer.
+  pub async fn parse<Parser, Context>(
+    &mut self, parser: &mut Parser,
+  ) -> Result<Parsed<Parser::Token, AsyncStream, Context>, io::Error>
+  where
+    Parser: Parse<AsyncStream, Context>,
+  {
+    loop {
+      let stream = self.stream();
+      let needed = Rc::clone(&stream.needed);
+      match parser.parse(stream) {
+        Parsed::Success { token, stream } => {
+          self.used = stream.offset();
+          return Ok(Parsed::Success { token, stream });
+        }
+        Parsed::Error(context) => match needed.get() {
+          Some(needed) if !self.eof => {
+            drop(context);
+            self.fill(needed).await?;
+          }
+          _ => return Ok(Parsed::Error(context)),
+        },
+        parsed => return Ok(parsed),
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::{
+    pin::Pin,
+    task::{
+      Context,
+      Poll,
+    },
+  };
+  use std::io;
+
+  use futures::{
+    executor::block_on,
+    io::{
+      AsyncRead,
+      Cursor,
+    },
+  };
+
+  use super::{
+    AsyncReader,
+    AsyncStream,
+  };
+  use crate::{
+    base::{
+      is,
+      octet,
+      primitive::u16_be,
+      take,
+    },
+    context::Ignore,
+    stream::VecStream,
+    utils::Utils,
+    Parse,
+    Parsed,
+  };
+
+  // return at most one octet by read and Pending every other call
+  struct Slow {
+    data: &'static [u8],
+    pending: bool,
+  }
+
+  impl AsyncRead for Slow {
+    fn poll_read(
+      mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+      self.pending = !self.pending;
+      if self.pending {
+        cx.waker().wake_by_ref();
+        return Poll::Pending;
+      }
+      match self.data.split_first() {
+        Some((&first, data)) if !buf.is_empty() => {
+          buf[0] = first;
+          self.data = data;
+          Poll::Ready(Ok(1))
+        }
+        _ => Poll::Ready(Ok(0)),
+      }
+    }
+  }
+
+  #[test]
+  fn frames() {
+    let mut reader = AsyncReader::<_, 2>::new(Slow {
+      data: b"\x00\x03abc\x00\x01d",
+      pending: false,
+    });
+    let mut frame = u16_be
+      .and_then(|len| take(usize::from(len)))
+      .map(|span: VecStream| span.as_ref().to_vec());
+    block_on(async {
+      let parsed: Parsed<_, _, Ignore> = reader.parse(&mut frame).await.unwrap();
+      assert_eq!(parsed.unwrap().token, b"abc");
+      let parsed: Parsed<_, _, Ignore> = reader.parse(&mut frame).await.unwrap();
+      assert_eq!(parsed.unwrap().token, b"d");
+      let parsed: Parsed<_, _, Ignore> = reader.parse(&mut frame).await.unwrap();
+      assert_eq!(parsed, Parsed::Failure(Ignore));
+      assert!(reader.is_eof());
+    });
+  }
+
+  #[test]
+  fn failure() {
+    let mut reader = AsyncReader::<_, 4>::new(Cursor::new(&b"abcdefg"[..]));
+    block_on(async {
+      let parsed: Parsed<_, _, Ignore> = reader.parse(&mut is(b'b')).await.unwrap();
+      assert_eq!(parsed, Parsed::Failure(Ignore));
+      let parsed: Parsed<_, _, Ignore> = reader.parse(&mut octet).await.unwrap();
+      assert_eq!(parsed.unwrap().token, b'a');
+      assert_eq!(reader.buffer(), b"bcd");
+    });
+  }
+
+  #[test]
+  fn parse_once_by_fill() {
+    let mut reader = AsyncReader::<_, 4>::new(Cursor::new(&b"0123456789"[..]));
+    let mut called = 0_usize;
+    let mut parser = |stream: AsyncStream| -> Parsed<_, _, Ignore> {
+      called += 1;
+      take(10).parse(stream)
+    };
+    block_on(async {
+      let parsed = reader.parse(&mut parser).await.unwrap();
+      assert_eq!(parsed.unwrap().token.as_ref(), b"0123456789");
+    });
+    assert_eq!(called, 2);
+  }
+}
@@ -1,152 +1,392 @@
 use core::{
-  mem::size_of,
-  ops::{
-    BitAnd,
-    Shl,
-  },
+  cmp::min,
+  fmt::Debug,
+  marker::PhantomData,
 };
 
 use crate::{
-  SplitError,
-  SplitFirst,
-  UtilsStream,
+  Split,
+  StreamPosition,
+  Streaming,
+  Success,
 };
-use num_traits::One;
 
-const fn bits_of<Item>() -> usize {
-  size_of::<Item>() * u8::BITS as usize
+/// Order used by [BitStream] to read bits from an octet
+pub trait BitOrder {
+  /// Return the bit number i of octet, i start at 0 for the first bit read
+  fn bit(octet: u8, i: u8) -> bool;
 }
 
-#[derive(Clone, PartialEq, Eq)]
-pub struct BitStream<Stream: UtilsStream, Item = <Stream as UtilsStream>::Item> {
+/// Most significant bit first, 0b1000_0000 produce true then seven false
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Msb;
+
+impl BitOrder for Msb {
+  fn bit(octet: u8, i: u8) -> bool {
+    octet & (0x80 >> i) != 0
+  }
+}
+
+/// Least significant bit first, 0b0000_0001 produce true then seven false
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Lsb;
+
+impl BitOrder for Lsb {
+  fn bit(octet: u8, i: u8) -> bool {
+    octet & (0x01 << i) != 0
+  }
+}
+
+/// A stream that produce bits from a stream of octet.
+/// Span are BitStream bounded to the number of bits requested.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BitStream<Stream, Order = Msb> {
+  // stream after cur
   stream: Stream,
-  i: usize,
-  cur: Item,
+  cur: u8,
+  // number of bits not yet read in cur
+  remaining: u8,
+  // number of bits read since the creation of the BitStream
+  position: usize,
+  // number of bits allowed, None if unbounded
+  len: Option<usize>,
+  order: PhantomData<Order>,
 }
 
-impl<Stream> From<Stream> for BitStream<Stream>
-where
-  Stream: UtilsStream,
-  <Stream as UtilsStream>::Item: Default,
-{
+impl<Stream, Order> From<Stream> for BitStream<Stream, Order> {
   fn from(stream: Stream) -> Self {
     Self::new(stream)
   }
 }
 
-impl<Stream: UtilsStream> BitStream<Stream> {
-  pub fn new(stream: Stream) -> Self
-  where
-    <Stream as UtilsStream>::Item: Default,
-  {
+impl<Stream, Order> BitStream<Stream, Order> {
+  /// Return a new BitStream that will read bits of octet from stream
+  pub const fn new(stream: Stream) -> Self {
     Self {
       stream,
-      i: bits_of::<<Stream as UtilsStream>::Item>(),
-      cur: Default::default(),
+      cur: 0,
+      remaining: 0,
+      position: 0,
+      len: None,
+      order: PhantomData,
     }
   }
 
-  fn next(self) -> (bool, Self)
-  where
-    for<'a> &'a <Stream as UtilsStream>::Item: BitAnd<Token = <Stream as UtilsStream>::Item>,
-    <Stream as UtilsStream>::Item: Shl<usize, Token = <Stream as UtilsStream>::Item>,
-    <Stream as UtilsStream>::Item: PartialEq,
-    <Stream as UtilsStream>::Item: One,
-  {
-    let flag = <Stream as UtilsStream>::Item::one() << self.i;
-    (
-      &self.cur & &flag == flag,
-      Self {
-        stream: self.stream,
-        i: self.i + 1,
-        cur: self.cur,
-      },
-    )
+  /// Return the number of bits read since the creation of the BitStream
+  pub const fn position(&self) -> usize {
+    self.position
   }
 
-  pub fn into_stream(self) -> Stream {
-    self.stream
+  /// Return true if the BitStream is at an octet boundary
+  pub const fn is_aligned(&self) -> bool {
+    self.remaining == 0
   }
-}
 
-impl<Stream: UtilsStream> UtilsStream for BitStream<Stream> {
-  type Error = <Stream as UtilsStream>::Error;
-  type Item = bool;
+  /// Drop the bits not read of the current octet, so the BitStream is at an
+  /// octet boundary
+  pub fn align(self) -> Self {
+    let skip = match self.len {
+      Some(len) => min(len, usize::from(self.remaining)),
+      None => usize::from(self.remaining),
+    };
+    Self {
+      remaining: 0,
+      position: self.position + skip,
+      len: self.len.map(|len| len - skip),
+      ..self
+    }
+  }
+
+  /// Return the octet stream if the BitStream is at an octet boundary.
+  /// Bound of a span are lost.
+  pub fn into_stream(self) -> Result<Stream, Self> {
+    if self.is_aligned() {
+      Ok(self.stream)
+    } else {
+      Err(self)
+    }
+  }
 }
 
-impl<Stream: SplitFirst> SplitFirst for BitStream<Stream>
+impl<Stream, Order> BitStream<Stream, Order>
 where
-  for<'a> &'a <Stream as UtilsStream>::Item: BitAnd<Token = <Stream as UtilsStream>::Item>,
-  <Stream as UtilsStream>::Item: Shl<usize, Token = <Stream as UtilsStream>::Item>,
-  <Stream as UtilsStream>::Item: PartialEq,
-  <Stream as UtilsStream>::Item: One,
+  Stream: Streaming,
+  Stream::Item: Into<u8>,
+  Order: BitOrder + Clone + Debug + Eq,
 {
-  fn split_first(self) -> Result<(Self::Item, Self), SplitError<Self, Self::Error>> {
-    if self.i == bits_of::<<Stream as UtilsStream>::Item>() {
-      let (cur, stream) = self.stream.split_first().map_err(|e| {
-        e.map(|stream| {
-          Self {
-            stream,
-            i: self.i,
-            cur: self.cur,
-          }
-        })
-      })?;
+  // number of bits left, read all the stream if unbounded
+  fn count(&self) -> Result<usize, Stream::Error> {
+    if let Some(len) = self.len {
+      return Ok(len);
+    }
 
-      Ok(
-        Self {
-          stream,
-          i: 0,
-          cur,
+    let mut count = usize::from(self.remaining);
+    let mut stream = self.stream.clone();
+    loop {
+      match stream.split_first() {
+        Split::Success { stream: next, .. } => {
+          count += 8;
+          stream = next;
         }
-        .next(),
-      )
+        Split::NotEnoughItem(_) => break Ok(count),
+        Split::Error(error) => break Err(error),
+      }
+    }
+  }
+}
+
+impl<Stream, Order> Streaming for BitStream<Stream, Order>
+where
+  Stream: Streaming,
+  Stream::Item: Into<u8>,
+  Order: BitOrder + Clone + Debug + Eq,
+{
+  type Error = Stream::Error;
+  type Item = bool;
+  type Span = Self;
+
+  fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
+    if self.len == Some(0) {
+      return Split::NotEnoughItem(self);
+    }
+
+    let (cur, remaining, stream) = if self.remaining == 0 {
+      match self.stream.clone().split_first() {
+        Split::Success { item, stream } => (item.into(), 8, stream),
+        Split::NotEnoughItem(_) => return Split::NotEnoughItem(self),
+        Split::Error(error) => return Split::Error(error),
+      }
     } else {
-      Ok(self.next())
+      (self.cur, self.remaining, self.stream)
+    };
+
+    Split::Success {
+      item: Order::bit(cur, 8 - remaining),
+      stream: Self {
+        stream,
+        cur,
+        remaining: remaining - 1,
+        position: self.position + 1,
+        len: self.len.map(|len| len - 1),
+        order: PhantomData,
+      },
+    }
+  }
+
+  fn split_at(self, mid: usize) -> Split<Self::Span, Self, Self::Error> {
+    if matches!(self.len, Some(len) if mid > len) {
+      return Split::NotEnoughItem(self);
+    }
+
+    let from_cur = min(mid, usize::from(self.remaining));
+    let (octets, bits) = ((mid - from_cur) / 8, ((mid - from_cur) % 8) as u8);
+    let (mut cur, mut remaining, mut stream) = (
+      self.cur,
+      self.remaining - from_cur as u8,
+      self.stream.clone(),
+    );
+
+    if octets > 0 {
+      match stream.split_at(octets) {
+        Split::Success { stream: next, .. } => stream = next,
+        Split::NotEnoughItem(_) => return Split::NotEnoughItem(self),
+        Split::Error(error) => return Split::Error(error),
+      }
+    }
+    if bits > 0 {
+      match stream.split_first() {
+        Split::Success { item, stream: next } => {
+          cur = item.into();
+          remaining = 8 - bits;
+          stream = next;
+        }
+        Split::NotEnoughItem(_) => return Split::NotEnoughItem(self),
+        Split::Error(error) => return Split::Error(error),
+      }
+    }
+
+    Split::Success {
+      stream: Self {
+        stream,
+        cur,
+        remaining,
+        position: self.position + mid,
+        len: self.len.map(|len| len - mid),
+        order: PhantomData,
+      },
+      item: Self {
+        len: Some(mid),
+        ..self
+      },
+    }
+  }
+
+  fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
+    let len = match self.count() {
+      Ok(0) => return Split::NotEnoughItem(self),
+      Ok(len) => len,
+      Err(error) => return Split::Error(error),
+    };
+
+    match self.clone().split_at(len - 1) {
+      Split::Success { stream: last, .. } => match last.split_first() {
+        Split::Success { item, .. } => Split::Success {
+          item,
+          stream: Self {
+            len: Some(len - 1),
+            ..self
+          },
+        },
+        Split::NotEnoughItem(_) => Split::NotEnoughItem(self),
+        Split::Error(error) => Split::Error(error),
+      },
+      Split::NotEnoughItem(_) => Split::NotEnoughItem(self),
+      Split::Error(error) => Split::Error(error),
+    }
+  }
+
+  fn all(self) -> Result<Success<Self::Span, Self>, Self::Error> {
+    let len = self.count()?;
+    match self.split_at(len) {
+      Split::Success { item, stream } => Ok(Success {
+        token: item,
+        stream,
+      }),
+      Split::NotEnoughItem(_) => unreachable!(),
+      Split::Error(error) => Err(error),
     }
   }
+
+  fn diff(self, other: &Self) -> Result<Self::Span, Self> {
+    let len = other.position.checked_sub(self.position);
+    match len {
+      Some(len)
+        if self.len.is_none_or(|max| len <= max)
+          && self.stream.clone().diff(&other.stream).is_ok() =>
+      {
+        Ok(Self {
+          len: Some(len),
+          ..self
+        })
+      }
+      _ => Err(self),
+    }
+  }
+
+  fn cursor(&self) -> Option<usize> {
+    Some(self.position)
+  }
+}
+
+/// Offset is the number of bits read since the creation of the BitStream
+impl<Stream, Order> StreamPosition for BitStream<Stream, Order>
+where
+  Stream: Streaming,
+  Stream::Item: Into<u8>,
+  Order: BitOrder + Clone + Debug + Eq,
+{
+  fn offset(&self) -> usize {
+    self.position
+  }
 }
 
-// impl<Reader: Read, const N: usize> Subset for BitStream<Reader, N> {
-//   fn subset(&self, other: &Self) -> Option<Self> {
-//     match (&self.position, &other.position) {
-//       (Position::RangeFrom(a), Position::RangeFrom(b)) => self.range(a.start,
-// b.start),       (Position::RangeFrom(a), Position::Range(b)) =>
-// self.range(a.start, b.start),       (Position::Range(a),
-// Position::RangeFrom(b)) => self.range(a.start, b.start),       (Position::
-// Range(a), Position::Range(b)) => self.range(a.start, b.start),     }
-//   }
-// }
-
-// impl<Reader: Read, const N: usize> SplitEnd for BitStream<Reader, N> {
-//   fn split_end(self) -> Result<(Self, Self), (Self::Error, Self)> {
-//     match &self.position {
-//       Position::RangeFrom(range) => {
-//         let ret = self.buf.borrow_mut().read_all();
-//         match ret {
-//           Ok(end) => {
-//             let empty = Self {
-//               buf: self.buf.clone(),
-//               position: Position::Range(end..end),
-//             };
-//             Ok((
-//               Self {
-//                 buf: self.buf,
-//                 position: Position::Range(range.start..end),
-//               },
-//               empty,
-//             ))
-//           }
-//           Err(error) => Err((error, self)),
-//         }
-//       }
-//       Position::Range(range) => {
-//         let empty = Self {
-//           buf: self.buf.clone(),
-//           position: Position::Range(range.end..range.end),
-//         };
-//         Ok((self, empty))
-//       }
-//     }
-//   }
-// }
+#[cfg(test)]
+mod tests {
+  use super::{
+    BitStream,
+    Lsb,
+    Msb,
+  };
+  use crate::{
+    base::{
+      any,
+      end_of_stream,
+      octet,
+    },
+    context::Ignore,
+    utils::Utils,
+    Parse,
+    Parsed,
+    Split,
+    Streaming,
+    Success,
+  };
+
+  fn bits<Stream: Streaming<Item = bool>, const N: usize>(stream: Stream) -> [bool; N] {
+    any::<_, Ignore>
+      .fill()
+      .and_drop(end_of_stream)
+      .parse(stream)
+      .unwrap()
+      .token
+  }
+
+  #[test]
+  fn order() {
+    let stream = &[0b1010_0001][..];
+    assert_eq!(
+      bits(BitStream::<_, Msb>::new(stream)),
+      [true, false, true, false, false, false, false, true]
+    );
+    assert_eq!(
+      bits(BitStream::<_, Lsb>::new(stream)),
+      [true, false, false, false, false, true, false, true]
+    );
+  }
+
+  #[test]
+  fn split_at() {
+    let stream = BitStream::<_, Msb>::new(&[0b1111_0000, 0b1010_1010, 0b1100_0011][..]);
+    let Split::Success { item, stream } = stream.split_at(6) else {
+      panic!()
+    };
+    assert_eq!(bits(item), [true, true, true, true, false, false]);
+    let Split::Success { item, stream } = stream.split_at(12) else {
+      panic!()
+    };
+    assert_eq!(
+      bits(item),
+      [false, false, true, false, true, false, true, false, true, false, true, true]
+    );
+    assert_eq!(stream.position(), 18);
+    assert!(matches!(
+      stream.clone().split_at(7),
+      Split::NotEnoughItem(_)
+    ));
+    assert_eq!(bits(stream), [false, false, false, false, true, true]);
+  }
+
+  #[test]
+  fn split_last() {
+    let stream = BitStream::<_, Msb>::new(&[0b1000_0001, 0b0100_0000][..]);
+    let Split::Success { item, stream } = stream.split_last() else {
+      panic!()
+    };
+    assert!(!item);
+    let Split::Success { stream, .. } = stream.split_at(9) else {
+      panic!()
+    };
+    assert_eq!(bits(stream), [true, false, false, false, false, false]);
+  }
+
+  #[test]
+  fn span() {
+    let stream = BitStream::<_, Msb>::new(&[0b1011_0000][..]);
+    let parsed: Parsed<_, _, Ignore> = any.fill::<3>().span().parse(stream);
+    let Success { token, stream } = parsed.unwrap();
+    assert_eq!(token.token, [true, false, true]);
+    assert_eq!(bits(token.stream), [true, false, true]);
+    assert_eq!(stream.position(), 3);
+  }
+
+  #[test]
+  fn into_stream() {
+    let stream = BitStream::<_, Msb>::new(&[0b1000_0000, 42][..]);
+    let Success { token, stream } = any::<_, Ignore>.parse(stream).unwrap();
+    assert!(token);
+    let stream = stream.into_stream().unwrap_err().align();
+    assert_eq!(stream.position(), 8);
+    let stream = stream.into_stream().unwrap();
+    assert_eq!(octet::<_, Ignore>(stream), Parsed::new_success(42, &[][..]));
+  }
+}
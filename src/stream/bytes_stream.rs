@@ -0,0 +1,146 @@
+use core::convert::Infallible;
+
+use bytes::Bytes;
+
+use crate::{
+  Split,
+  StreamPosition,
+  Streaming,
+  Success,
+};
+
+/// Span are cheap refcounted slice of the input that can outlive the parse
+impl Streaming for Bytes {
+  type Error = Infallible;
+  type Item = u8;
+  type Span = Bytes;
+
+  fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
+    match self.first() {
+      Some(&o) => Split::Success {
+        item: o,
+        stream: self.slice(1..),
+      },
+      None => Split::NotEnoughItem(self),
+    }
+  }
+
+  fn split_at(mut self, mid: usize) -> Split<Self::Span, Self, Self::Error> {
+    if mid <= self.len() {
+      Split::Success {
+        item: self.split_to(mid),
+        stream: self,
+      }
+    } else {
+      Split::NotEnoughItem(self)
+    }
+  }
+
+  fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
+    match self.last() {
+      Some(&o) => Split::Success {
+        item: o,
+        stream: self.slice(..self.len() - 1),
+      },
+      None => Split::NotEnoughItem(self),
+    }
+  }
+
+  fn all(self) -> Result<Success<Self::Span, Self>, Self::Error> {
+    Ok(Success {
+      stream: self.slice(self.len()..),
+      token: self,
+    })
+  }
+
+  fn diff(self, other: &Self) -> Result<Self::Span, Self> {
+    match self
+      .len()
+      .checked_sub(other.len())
+      .filter(|&offset| self[offset..].as_ptr() == other.as_ptr())
+    {
+      Some(offset) => Ok(self.slice(..offset)),
+      None => Err(self),
+    }
+  }
+
+  fn cursor(&self) -> Option<usize> {
+    Some(self.as_ptr() as usize)
+  }
+}
+
+/// Offset is the address of the cursor
+impl StreamPosition for Bytes {
+  fn offset(&self) -> usize {
+    self.as_ptr() as usize
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use bytes::Bytes;
+
+  use crate::{
+    base::{
+      primitive::u16_be,
+      tag,
+      take,
+    },
+    context::Ignore,
+    utils::Utils,
+    Parse,
+    Split,
+    Streaming,
+    Success,
+  };
+
+  fn stream() -> Bytes {
+    Bytes::from_static(b"abcd")
+  }
+
+  #[test]
+  fn split() {
+    let Split::Success { item, stream } = stream().split_first() else {
+      panic!()
+    };
+    assert_eq!(item, b'a');
+    let Split::Success { item, stream } = stream.split_last() else {
+      panic!()
+    };
+    assert_eq!(item, b'd');
+    let Split::Success { item, stream } = stream.split_at(1) else {
+      panic!()
+    };
+    assert_eq!(item, b"b"[..]);
+    assert_eq!(stream, b"c"[..]);
+    assert_eq!(stream.clone().split_at(2), Split::NotEnoughItem(stream));
+  }
+
+  #[test]
+  fn diff() {
+    let stream = Bytes::from(b"abcd".to_vec());
+    let Split::Success { stream: mid, .. } = stream.clone().split_at(2) else {
+      panic!()
+    };
+    assert_eq!(stream.clone().diff(&mid), Ok(Bytes::from_static(b"ab")));
+    assert_eq!(mid.clone().diff(&stream), Err(mid));
+    let Success { stream: end, .. } = stream.clone().all().unwrap();
+    assert_eq!(stream.clone().diff(&end), Ok(stream.clone()));
+    let other = Bytes::from(b"abcd".to_vec());
+    assert_eq!(stream.clone().diff(&other), Err(stream));
+  }
+
+  #[test]
+  fn frame_outlive_input() {
+    let frame = {
+      let stream = Bytes::from(b"BIN\x00\x02hi!".to_vec());
+      let Success { token, .. } = tag::<_, Ignore>("BIN")
+        .drop_and(u16_be)
+        .and_then(|len| take(usize::from(len)))
+        .parse(stream)
+        .unwrap();
+      token
+    };
+    assert_eq!(frame, b"hi"[..]);
+  }
+}
@@ -0,0 +1,324 @@
+use alloc::rc::Rc;
+use core::{
+  cell::OnceCell,
+  cmp::min,
+  convert::Infallible,
+  fmt::Debug,
+  iter,
+};
+
+use crate::{
+  Split,
+  StreamPosition,
+  Streaming,
+  Success,
+};
+
+/// A stream that return octet from a list of non contiguous chunks without
+/// copying them, a chunk can be anything that implement `AsRef<[u8]>` like
+/// `&[u8]` or `Bytes`. Span are stream bounded to the number of octet
+/// requested, a Span that cross chunks boundaries copy itself when
+/// `AsRef<[u8]>` is used, the copy is shared by its clones.
+/// [ChunkStream::chunks] give access to the data without copy.
+#[derive(Debug)]
+pub struct ChunkStream<'a, Chunk = &'a [u8]> {
+  // current chunk, can only be empty at the end
+  first: &'a [u8],
+  rest: &'a [Chunk],
+  // number of octet left
+  len: usize,
+  copy: OnceCell<Rc<[u8]>>,
+}
+
+impl<'a, Chunk> Clone for ChunkStream<'a, Chunk> {
+  fn clone(&self) -> Self {
+    Self {
+      first: self.first,
+      rest: self.rest,
+      len: self.len,
+      copy: self.copy.clone(),
+    }
+  }
+}
+
+impl<'a, Chunk: AsRef<[u8]>> ChunkStream<'a, Chunk> {
+  /// Return a new `ChunkStream` from a list of chunks
+  pub fn new(chunks: &'a [Chunk]) -> Self {
+    Self::with(
+      &[],
+      chunks,
+      chunks.iter().map(|chunk| chunk.as_ref().len()).sum(),
+    )
+  }
+
+  fn with(mut first: &'a [u8], mut rest: &'a [Chunk], len: usize) -> Self {
+    while first.is_empty() {
+      match rest.split_first() {
+        Some((next, tail)) => {
+          first = next.as_ref();
+          rest = tail;
+        }
+        None => break,
+      }
+    }
+
+    Self {
+      first,
+      rest,
+      len,
+      copy: OnceCell::new(),
+    }
+  }
+
+  /// Return the number of octet left
+  pub const fn len(&self) -> usize {
+    self.len
+  }
+
+  /// Return true if there is no octet left
+  pub const fn is_empty(&self) -> bool {
+    self.len == 0
+  }
+
+  /// Return an iterator over the chunks of the stream, without copy
+  pub fn chunks(&self) -> impl Iterator<Item = &'a [u8]> + '_ {
+    iter::once(self.first)
+      .chain(self.rest.iter().map(AsRef::as_ref))
+      .scan(self.len, |len, chunk| {
+        (*len > 0).then(|| {
+          let chunk = &chunk[..min(*len, chunk.len())];
+          *len -= chunk.len();
+          chunk
+        })
+      })
+      .filter(|chunk| !chunk.is_empty())
+  }
+
+  // n must be less or equal than len
+  fn advance(&self, mut n: usize) -> Self {
+    let (mut first, mut rest) = (self.first, self.rest);
+    let len = self.len - n;
+    while n > first.len() {
+      n -= first.len();
+      (first, rest) = match rest.split_first() {
+        Some((next, tail)) => (next.as_ref(), tail),
+        None => unreachable!(),
+      };
+    }
+    Self::with(&first[n..], rest, len)
+  }
+}
+
+impl<'a, Chunk: AsRef<[u8]>> AsRef<[u8]> for ChunkStream<'a, Chunk> {
+  fn as_ref(&self) -> &[u8] {
+    if self.len <= self.first.len() {
+      &self.first[..self.len]
+    } else {
+      self
+        .copy
+        .get_or_init(|| self.chunks().flatten().copied().collect())
+    }
+  }
+}
+
+impl<'a, Chunk> PartialEq for ChunkStream<'a, Chunk> {
+  fn eq(&self, other: &Self) -> bool {
+    self.first.as_ptr() == other.first.as_ptr()
+      && self.first.len() == other.first.len()
+      && self.rest.as_ptr() == other.rest.as_ptr()
+      && self.rest.len() == other.rest.len()
+      && self.len == other.len
+  }
+}
+
+impl<'a, Chunk> Eq for ChunkStream<'a, Chunk> {}
+
+impl<'a, Chunk> Streaming for ChunkStream<'a, Chunk>
+where
+  Chunk: AsRef<[u8]> + Debug,
+{
+  type Error = Infallible;
+  type Item = u8;
+  type Span = Self;
+
+  fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
+    if self.len == 0 {
+      Split::NotEnoughItem(self)
+    } else {
+      Split::Success {
+        item: self.first[0],
+        stream: self.advance(1),
+      }
+    }
+  }
+
+  fn split_at(self, mid: usize) -> Split<Self, Self, Self::Error> {
+    if mid <= self.len {
+      Split::Success {
+        stream: self.advance(mid),
+        item: Self::with(self.first, self.rest, mid),
+      }
+    } else {
+      Split::NotEnoughItem(self)
+    }
+  }
+
+  fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
+    if self.len == 0 {
+      Split::NotEnoughItem(self)
+    } else {
+      let last = self.advance(self.len - 1);
+      Split::Success {
+        item: last.first[0],
+        stream: Self::with(self.first, self.rest, self.len - 1),
+      }
+    }
+  }
+
+  fn all(self) -> Result<Success<Self::Span, Self>, Self::Error> {
+    Ok(Success {
+      stream: self.advance(self.len),
+      token: self,
+    })
+  }
+
+  fn diff(self, other: &Self) -> Result<Self::Span, Self> {
+    match self.len.checked_sub(other.len) {
+      Some(len) if self.advance(len) == *other => Ok(Self::with(self.first, self.rest, len)),
+      _ => Err(self),
+    }
+  }
+
+  fn cursor(&self) -> Option<usize> {
+    Some(self.first.as_ptr() as usize)
+  }
+}
+
+/// Offset is the address of the cursor, chunks must not overlap
+impl<'a, Chunk> StreamPosition for ChunkStream<'a, Chunk>
+where
+  Chunk: AsRef<[u8]> + Debug,
+{
+  fn offset(&self) -> usize {
+    self.first.as_ptr() as usize
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use super::ChunkStream;
+  use crate::{
+    base::{
+      primitive::u32_be,
+      tag,
+      take,
+    },
+    context::Ignore,
+    utils::Utils,
+    Parse,
+    Split,
+    Streaming,
+    Success,
+  };
+
+  const CHUNKS: &[&[u8]] = &[b"ab", b"", b"cde", b"f"];
+
+  #[test]
+  fn split_first() {
+    let mut stream = ChunkStream::new(CHUNKS);
+    let mut octets = Vec::new();
+    while let Split::Success { item, stream: next } = stream.clone().split_first() {
+      octets.push(item);
+      stream = next;
+    }
+    assert_eq!(octets, b"abcdef");
+    assert!(stream.is_empty());
+  }
+
+  #[test]
+  fn split_at() {
+    let slice = b"abcdef";
+    for n in 0..=slice.len() {
+      let Split::Success { item, stream } = ChunkStream::new(CHUNKS).split_at(n) else {
+        panic!()
+      };
+      assert_eq!(item.as_ref(), &slice[..n]);
+      assert_eq!(stream.as_ref(), &slice[n..]);
+    }
+
+    let stream = ChunkStream::new(CHUNKS);
+    assert_eq!(
+      stream.clone().split_at(slice.len() + 1),
+      Split::NotEnoughItem(stream)
+    );
+  }
+
+  #[test]
+  fn split_last() {
+    let Split::Success { item, stream } = ChunkStream::new(CHUNKS).split_last() else {
+      panic!()
+    };
+    assert_eq!(item, b'f');
+    let Split::Success { item, stream } = stream.split_last() else {
+      panic!()
+    };
+    assert_eq!(item, b'e');
+    assert_eq!(stream.as_ref(), b"abcd");
+  }
+
+  #[test]
+  fn all() {
+    let stream = ChunkStream::new(CHUNKS);
+    let Success { token, stream: end } = stream.clone().all().unwrap();
+    assert_eq!(token, stream);
+    assert!(end.is_empty());
+    assert_eq!(stream.diff(&end).unwrap().as_ref(), b"abcdef");
+  }
+
+  #[test]
+  fn diff() {
+    let stream = ChunkStream::new(CHUNKS);
+    let Split::Success { stream: mid, .. } = stream.clone().split_at(3) else {
+      panic!()
+    };
+    assert_eq!(stream.clone().diff(&mid).unwrap().as_ref(), b"abc");
+    assert_eq!(mid.clone().diff(&stream), Err(mid));
+    let other = [CHUNKS[0], CHUNKS[2]];
+    assert_eq!(stream.clone().diff(&ChunkStream::new(&other)), Err(stream));
+  }
+
+  #[test]
+  fn chunks() {
+    let Split::Success { item, .. } = ChunkStream::new(CHUNKS).split_at(4) else {
+      panic!()
+    };
+    assert_eq!(item.chunks().collect::<Vec<_>>(), [&b"ab"[..], b"cd"]);
+  }
+
+  #[test]
+  fn base() {
+    let chunks: &[&[u8]] = &[b"BI", b"N\x00\x00", b"\x00\x03a", b"bc!"];
+    let Success { token, stream } = tag::<_, Ignore>("BIN")
+      .drop_and(u32_be)
+      .and_then(|len| take(len as usize))
+      .parse(ChunkStream::new(chunks))
+      .unwrap();
+    assert_eq!(token.as_ref(), b"abc");
+    assert_eq!(stream.as_ref(), b"!");
+  }
+
+  #[test]
+  fn owned_chunks() {
+    let chunks: Vec<Vec<u8>> = CHUNKS.iter().map(|chunk| chunk.to_vec()).collect();
+    let Split::Success { item, stream } = ChunkStream::new(&chunks).split_at(4) else {
+      panic!()
+    };
+    assert_eq!(item.as_ref(), b"abcd");
+    // the copy is shared by the clone
+    let clone = item.clone();
+    assert_eq!(clone.as_ref().as_ptr(), item.as_ref().as_ptr());
+    assert_eq!(stream.as_ref(), b"ef");
+  }
+}
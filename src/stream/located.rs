@@ -0,0 +1,475 @@
+use core::fmt::{
+  self,
+  Debug,
+  Display,
+  Formatter,
+};
+
+use crate::{
+  Contexting,
+  Parse,
+  Parsed,
+  Split,
+  StreamPosition,
+  Streaming,
+  Success,
+};
+
+/// Item that [Located] know how to locate
+pub trait LocatedItem {
+  /// Number of octet used by the item
+  fn octets(&self) -> usize;
+  /// Return true if the item end a line
+  fn is_newline(&self) -> bool;
+  /// Number of item in octets, the column they use
+  fn columns(octets: &[u8]) -> usize;
+}
+
+impl LocatedItem for u8 {
+  fn octets(&self) -> usize {
+    1
+  }
+
+  fn is_newline(&self) -> bool {
+    *self == b'\n'
+  }
+
+  fn columns(octets: &[u8]) -> usize {
+    octets.len()
+  }
+}
+
+impl LocatedItem for char {
+  fn octets(&self) -> usize {
+    self.len_utf8()
+  }
+
+  fn is_newline(&self) -> bool {
+    *self == '\n'
+  }
+
+  fn columns(octets: &[u8]) -> usize {
+    // count the octets that are not utf8 continuation
+    octets.iter().filter(|&&o| (o as i8) >= -0x40).count()
+  }
+}
+
+/// Span that [Located] can go through to know the [Location] after it.
+/// Implemented for every Span that implement `AsRef<[u8]>`, the newline are
+/// counted over the octets without split the Span item by item.
+pub trait LocatedSpan<Item> {
+  /// Return location moved after the Span
+  fn advance(&self, location: Location) -> Location;
+}
+
+impl<Span, Item> LocatedSpan<Item> for Span
+where
+  Span: AsRef<[u8]>,
+  Item: LocatedItem,
+{
+  fn advance(&self, location: Location) -> Location {
+    let octets = self.as_ref();
+    let offset = location.offset + octets.len();
+    match octets.iter().rposition(|&o| o == b'\n') {
+      Some(last) => Location {
+        offset,
+        line: location.line + octets.iter().filter(|&&o| o == b'\n').count(),
+        column: Item::columns(&octets[last + 1..]) + 1,
+      },
+      None => Location {
+        offset,
+        line: location.line,
+        column: location.column + Item::columns(octets),
+      },
+    }
+  }
+}
+
+/// Position in the input, line and column start at 1
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Location {
+  /// Number of octet before the position
+  pub offset: usize,
+  /// Line of the position
+  pub line: usize,
+  /// Column of the position, in item
+  pub column: usize,
+}
+
+impl Location {
+  /// Location of the start of an input
+  pub const START: Self = Self {
+    offset: 0,
+    line: 1,
+    column: 1,
+  };
+
+  fn advance<Item: LocatedItem>(self, item: &Item) -> Self {
+    if item.is_newline() {
+      Self {
+        offset: self.offset + item.octets(),
+        line: self.line + 1,
+        column: 1,
+      }
+    } else {
+      Self {
+        offset: self.offset + item.octets(),
+        line: self.line,
+        column: self.column + 1,
+      }
+    }
+  }
+}
+
+impl Default for Location {
+  fn default() -> Self {
+    Self::START
+  }
+}
+
+impl Display for Location {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "{}:{}", self.line, self.column)
+  }
+}
+
+/// A stream that wrap an other stream and keep track of the
+/// [Location] of the cursor. Span are the Span of the inner stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Located<Stream> {
+  stream: Stream,
+  location: Location,
+}
+
+impl<Stream> From<Stream> for Located<Stream> {
+  fn from(stream: Stream) -> Self {
+    Self::new(stream)
+  }
+}
+
+impl<Stream> Located<Stream> {
+  /// Return a new Located that start at [Location::START]
+  pub const fn new(stream: Stream) -> Self {
+    Self::with_location(stream, Location::START)
+  }
+
+  /// Return a new Located that start at location, useful if stream is not
+  /// the start of the input
+  pub const fn with_location(stream: Stream, location: Location) -> Self {
+    Self { stream, location }
+  }
+
+  /// Return the current location
+  pub const fn location(&self) -> Location {
+    self.location
+  }
+
+  /// Return the inner stream
+  pub fn into_inner(self) -> Stream {
+    self.stream
+  }
+}
+
+impl<Stream> Streaming for Located<Stream>
+where
+  Stream: Streaming,
+  Stream::Item: LocatedItem,
+  Stream::Span: LocatedSpan<Stream::Item>,
+{
+  type Error = Stream::Error;
+  type Item = Stream::Item;
+  type Span = Stream::Span;
+
+  fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
+    match self.stream.split_first() {
+      Split::Success { item, stream } => Split::Success {
+        stream: Self {
+          stream,
+          location: self.location.advance(&item),
+        },
+        item,
+      },
+      Split::NotEnoughItem(stream) => Split::NotEnoughItem(Self {
+        stream,
+        location: self.location,
+      }),
+      Split::Error(error) => Split::Error(error),
+    }
+  }
+
+  fn split_at(self, mid: usize) -> Split<Self::Span, Self, Self::Error> {
+    match self.stream.split_at(mid) {
+      Split::Success { item, stream } => Split::Success {
+        stream: Self {
+          stream,
+          location: item.advance(self.location),
+        },
+        item,
+      },
+      Split::NotEnoughItem(stream) => Split::NotEnoughItem(Self {
+        stream,
+        location: self.location,
+      }),
+      Split::Error(error) => Split::Error(error),
+    }
+  }
+
+  fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
+    match self.stream.split_last() {
+      Split::Success { item, stream } => Split::Success {
+        item,
+        stream: Self {
+          stream,
+          location: self.location,
+        },
+      },
+      Split::NotEnoughItem(stream) => Split::NotEnoughItem(Self {
+        stream,
+        location: self.location,
+      }),
+      Split::Error(error) => Split::Error(error),
+    }
+  }
+
+  fn all(self) -> Result<Success<Self::Span, Self>, Self::Error> {
+    let Success { token, stream } = self.stream.all()?;
+    let location = token.advance(self.location);
+    Ok(Success {
+      token,
+      stream: Self { stream, location },
+    })
+  }
+
+  fn diff(self, other: &Self) -> Result<Self::Span, Self> {
+    self.stream.diff(&other.stream).map_err(|stream| Self {
+      stream,
+      location: self.location,
+    })
+  }
+
+  fn require(&self, n: usize) -> Result<(), Self::Error> {
+    self.stream.require(n)
+  }
+
+  fn cursor(&self) -> Option<usize> {
+    self.stream.cursor()
+  }
+}
+
+/// Offset is the offset of the inner stream, not the one of the [Location]
+impl<Stream> StreamPosition for Located<Stream>
+where
+  Stream: StreamPosition,
+  Stream::Item: LocatedItem,
+  Stream::Span: LocatedSpan<Stream::Item>,
+{
+  fn offset(&self) -> usize {
+    self.stream.offset()
+  }
+}
+
+/// Parser that return the current [Location] of a [Located] stream without
+/// consuming anything
+pub const fn location<Stream, Context>(
+  stream: Located<Stream>,
+) -> Parsed<Location, Located<Stream>, Context> {
+  Parsed::Success {
+    token: stream.location(),
+    stream,
+  }
+}
+
+/// Atom that contains the [Location] where a parser started when it failed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LocationAtom {
+  /// Location of the stream given to the parser
+  pub location: Location,
+}
+
+impl Display for LocationAtom {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "at {}", self.location)
+  }
+}
+
+/// Implementation of [with_location]
+#[derive(Clone)]
+pub struct WithLocation<Parser> {
+  parser: Parser,
+}
+
+impl<Stream, Context, Parser> Parse<Located<Stream>, Context> for WithLocation<Parser>
+where
+  Parser: Parse<Located<Stream>, Context>,
+  Context: Contexting<LocationAtom>,
+{
+  type Token = Parser::Token;
+
+  fn parse(&mut self, stream: Located<Stream>) -> Parsed<Self::Token, Located<Stream>, Context> {
+    let location = stream.location();
+    self
+      .parser
+      .parse(stream)
+      .add_context(|| LocationAtom { location })
+  }
+}
+
+/// If the parser is not successful a [LocationAtom] with the location where
+/// the parser started is added to the Context
+pub const fn with_location<Stream, Context, Parser>(parser: Parser) -> WithLocation<Parser>
+where
+  Parser: Parse<Located<Stream>, Context>,
+  Context: Contexting<LocationAtom>,
+{
+  WithLocation { parser }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::convert::Infallible;
+
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use super::{
+    location,
+    with_location,
+    Located,
+    Location,
+    LocationAtom,
+  };
+  use crate::{
+    base::{
+      is,
+      tag,
+      take,
+      BaseAtom,
+    },
+    context::{
+      Ignore,
+      Keep,
+      Last,
+    },
+    utils::{
+      Utils,
+      UtilsAtom,
+    },
+    CoreAtom,
+    Parse,
+    Parsed,
+    ProvideElement,
+    Split,
+    Streaming,
+    Success,
+  };
+
+  #[derive(Display, Debug, Clone, From, PartialEq)]
+  enum FromAtom<Stream: Streaming> {
+    Utils(UtilsAtom<Stream>),
+    Base(BaseAtom<u8>),
+    Core(CoreAtom<Stream, Infallible>),
+    Location(LocationAtom),
+  }
+
+  #[test]
+  fn split_first() {
+    let mut stream = Located::new("a\nbé\nc");
+    let mut locations = [Location::START; 6];
+    for location in locations.iter_mut() {
+      let Split::Success { stream: next, .. } = stream.split_first() else {
+        panic!()
+      };
+      stream = next;
+      *location = stream.location();
+    }
+    assert_eq!(
+      locations.map(|location| (location.offset, location.line, location.column)),
+      [
+        (1, 1, 2),
+        (2, 2, 1),
+        (3, 2, 2),
+        (5, 2, 3),
+        (6, 3, 1),
+        (7, 3, 2)
+      ]
+    );
+  }
+
+  #[test]
+  fn split_at() {
+    let stream = Located::new("ab\ncd".as_bytes());
+    let parsed: Parsed<_, _, Ignore> = take(4).drop_and(location).parse(stream);
+    let Success { token, stream } = parsed.unwrap();
+    assert_eq!(
+      token,
+      Location {
+        offset: 4,
+        line: 2,
+        column: 2,
+      }
+    );
+    assert_eq!(stream.into_inner(), "d".as_bytes());
+
+    // column count char not octet
+    let stream = Located::new("é\naé€b");
+    let parsed: Parsed<_, _, Ignore> = take(4).drop_and(location).parse(stream);
+    let Success { token, .. } = parsed.unwrap();
+    assert_eq!(
+      token,
+      Location {
+        offset: 6,
+        line: 2,
+        column: 3,
+      }
+    );
+  }
+
+  #[cfg(feature = "std")]
+  #[test]
+  fn reader_stream() {
+    use std::io::Cursor;
+
+    use crate::stream::ReaderStream;
+
+    let stream = Located::new(ReaderStream::<_, 2>::new(Cursor::new(&b"ab\ncd"[..])));
+    let parsed: Parsed<_, _, Ignore> = take(4).drop_and(location).parse(stream);
+    let Success { token, .. } = parsed.unwrap();
+    assert_eq!(token.line, 2);
+    assert_eq!(token.column, 2);
+  }
+
+  #[test]
+  fn cursor() {
+    let input = "ab\ncd";
+    let parsed: Parsed<_, _, Ignore> = take(4).parse(Located::new(input));
+    let Success { stream, .. } = parsed.unwrap();
+    assert_eq!(stream.cursor(), (&input[4..]).cursor());
+    assert_eq!(stream.location().offset, 4);
+  }
+
+  #[test]
+  fn failure() {
+    let stream = Located::new("let\n x".as_bytes());
+    let parsed: Parsed<_, _, Keep<Last, FromAtom<_>>> = tag("let")
+      .and(is(b'\n'))
+      .and(with_location(tag("let")))
+      .parse(stream);
+    let Parsed::Failure(context) = parsed else {
+      panic!()
+    };
+    assert_eq!(
+      context.last(),
+      &FromAtom::Location(LocationAtom {
+        location: Location {
+          offset: 4,
+          line: 2,
+          column: 1,
+        },
+      })
+    );
+    assert_eq!(context.last().to_string(), "at 2:1");
+  }
+}
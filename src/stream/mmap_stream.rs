@@ -0,0 +1,123 @@
+use std::{
+  fs::File,
+  io,
+};
+
+use memmap2::Mmap;
+
+use crate::stream::VecStream;
+
+/// A stream that return octet from a memory mapped file.
+/// The stream own the mapping, Span are cheap clone of the stream
+/// that give access to a `&[u8]`
+pub type MmapStream = VecStream<Mmap>;
+
+impl VecStream<Mmap> {
+  /// Map file in memory and return a new `MmapStream` of it
+  ///
+  /// # Safety
+  ///
+  /// See `memmap2::Mmap::map`, the file must not be modified while mapped
+  pub unsafe fn map(file: &File) -> Result<Self, io::Error> {
+    Ok(Self::new(unsafe { Mmap::map(file)? }))
+  }
+}
+
+impl From<Mmap> for MmapStream {
+  fn from(mmap: Mmap) -> Self {
+    Self::new(mmap)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{
+    env,
+    fs::{
+      self,
+      File,
+    },
+    path::PathBuf,
+    process,
+  };
+
+  use super::MmapStream;
+  use crate::{
+    base::{
+      primitive::u16_be,
+      tag,
+      take,
+    },
+    context::Ignore,
+    utils::Utils,
+    Parse,
+    Split,
+    Streaming,
+    Success,
+  };
+
+  // file is removed when dropped
+  struct TempFile(PathBuf);
+
+  impl TempFile {
+    fn new(name: &str, data: &[u8]) -> Self {
+      let path = env::temp_dir().join(format!("binator-{}-{}", process::id(), name));
+      fs::write(&path, data).unwrap();
+      Self(path)
+    }
+
+    fn stream(&self) -> MmapStream {
+      unsafe { MmapStream::map(&File::open(&self.0).unwrap()).unwrap() }
+    }
+  }
+
+  impl Drop for TempFile {
+    fn drop(&mut self) {
+      let _ = fs::remove_file(&self.0);
+    }
+  }
+
+  #[test]
+  fn split() {
+    let file = TempFile::new("split", b"abcd");
+    let stream = file.stream();
+    let Split::Success { item, stream } = stream.split_first() else {
+      panic!()
+    };
+    assert_eq!(item, b'a');
+    let Split::Success { item, stream } = stream.split_last() else {
+      panic!()
+    };
+    assert_eq!(item, b'd');
+    let Split::Success { item, stream } = stream.split_at(1) else {
+      panic!()
+    };
+    assert_eq!(item.as_ref(), b"b");
+    assert_eq!(stream.as_ref(), b"c");
+    assert_eq!(stream.clone().split_at(2), Split::NotEnoughItem(stream));
+  }
+
+  #[test]
+  fn diff() {
+    let file = TempFile::new("diff", b"abcd");
+    let stream = file.stream();
+    let Split::Success { stream: mid, .. } = stream.clone().split_at(2) else {
+      panic!()
+    };
+    assert_eq!(stream.clone().diff(&mid).unwrap().as_ref(), b"ab");
+    assert_eq!(mid.clone().diff(&stream), Err(mid));
+    assert_eq!(stream.clone().diff(&file.stream()), Err(stream));
+  }
+
+  #[test]
+  fn base() {
+    let file = TempFile::new("base", b"BIN\x00\x02hi!");
+    let Success { token, stream } = tag::<_, Ignore>("BIN")
+      .drop_and(u16_be)
+      .and_then(|len| take(usize::from(len)))
+      .parse(file.stream())
+      .unwrap();
+    assert_eq!(token.as_ref(), b"hi");
+    assert_eq!(stream.as_ref(), b"!");
+  }
+}
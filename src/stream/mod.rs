@@ -6,29 +6,59 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+mod located;
+pub use located::*;
+
+mod partial;
+pub use partial::*;
+
 #[cfg(feature = "std")]
 mod reader_stream;
 #[cfg(feature = "std")]
 pub use reader_stream::*;
 
+mod state_stream;
+pub use state_stream::*;
+
+#[cfg(feature = "alloc")]
+mod chunk_stream;
+#[cfg(feature = "alloc")]
+pub use chunk_stream::*;
+
+#[cfg(feature = "alloc")]
+mod string_stream;
+#[cfg(feature = "alloc")]
+pub use string_stream::*;
+
 #[cfg(feature = "std")]
 mod vec_stream;
-// mod string_stream;
-// pub use string_stream::*;
+#[cfg(feature = "std")]
+pub use vec_stream::*;
+
+#[cfg(feature = "bit_stream")]
+mod bit_stream;
+#[cfg(feature = "bit_stream")]
+pub use bit_stream::*;
+
+#[cfg(feature = "async_reader")]
+mod async_reader;
+#[cfg(feature = "async_reader")]
+pub use async_reader::*;
+
+#[cfg(feature = "mmap")]
+mod mmap_stream;
+#[cfg(feature = "mmap")]
+pub use mmap_stream::*;
+
+#[cfg(feature = "bytes")]
+mod bytes_stream;
 
-// #[cfg(feature = "bit_stream")]
-// mod bit_stream;
-// #[cfg(feature = "bit_stream")]
-// pub use bit_stream::*;
 #[cfg(feature = "std")]
 use core::ops::{
   Range,
   RangeFrom,
 };
 
-#[cfg(feature = "std")]
-pub use vec_stream::*;
-
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg(feature = "std")]
 pub(crate) enum Position {
@@ -51,14 +81,27 @@ impl Position {
     }
   }
 
-  fn range(&self, b: &Self) -> Option<Range<usize>> {
+  const fn start(&self) -> usize {
+    match self {
+      Position::RangeFrom(from) => from.start,
+      Position::Range(range) => range.start,
+    }
+  }
+
+  /// Return the range between self and b, b must be a later position of self,
+  /// len is the number of item when all was read
+  fn range(&self, b: &Self, len: usize) -> Option<Range<usize>> {
     let (a, b) = match (self, b) {
       (Position::RangeFrom(a), Position::RangeFrom(b)) => (a.start, b.start),
-      (Position::RangeFrom(a), Position::Range(b)) => (a.start, b.start),
-      (Position::Range(a), Position::RangeFrom(b)) => (a.start, b.start),
-      (Position::Range(a), Position::Range(b)) => (a.start, b.start),
+      // b must end at the end, like the one returned by all
+      (Position::RangeFrom(a), Position::Range(b)) if b.end == len => (a.start, b.start),
+      (Position::RangeFrom(_), Position::Range(_)) => return None,
+      // a RangeFrom can't come from a Range
+      (Position::Range(_), Position::RangeFrom(_)) => return None,
+      (Position::Range(a), Position::Range(b)) if a.end == b.end => (a.start, b.start),
+      (Position::Range(_), Position::Range(_)) => return None,
     };
 
-    if a >= b { Some(a..a - b) } else { None }
+    if a <= b { Some(a..b) } else { None }
   }
 }
@@ -0,0 +1,364 @@
+use core::fmt::{
+  self,
+  Debug,
+  Display,
+  Formatter,
+};
+
+use crate::{
+  Split,
+  StreamPosition,
+  Streaming,
+  Success,
+};
+
+/// Error of a [Partial] stream
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartialError<Error> {
+  /// The stream doesn't have enough data yet
+  Incomplete {
+    /// Number of Item missing, None if it can't be known
+    needed: Option<usize>,
+  },
+  /// Error returned by the inner stream
+  Stream(Error),
+}
+
+impl<Error> PartialError<Error> {
+  /// Return the number of Item needed if self is Incomplete
+  pub const fn needed(&self) -> Option<usize> {
+    match self {
+      Self::Incomplete { needed } => *needed,
+      Self::Stream(_) => None,
+    }
+  }
+}
+
+impl<Error: Display> Display for PartialError<Error> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::Incomplete {
+        needed: Some(needed),
+      } => write!(f, "Incomplete, {} more needed", needed),
+      Self::Incomplete { needed: None } => write!(f, "Incomplete"),
+      Self::Stream(error) => write!(f, "{}", error),
+    }
+  }
+}
+
+/// A stream that wrap an other stream that doesn't contain all the input
+/// yet. When there is not enough Item the stream return
+/// [PartialError::Incomplete] instead of reporting the end of the stream, so
+/// parser return an Error with a [crate::CoreAtom::Error] and the caller can
+/// read more data and parse again. When all data are available call
+/// [Partial::complete] to get back the normal end of stream behavior.
+/// Span are the Span of the inner stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Partial<Stream> {
+  stream: Stream,
+  complete: bool,
+}
+
+impl<Stream> From<Stream> for Partial<Stream> {
+  fn from(stream: Stream) -> Self {
+    Self::new(stream)
+  }
+}
+
+impl<Stream> Partial<Stream> {
+  /// Return a new Partial that will report missing data
+  pub const fn new(stream: Stream) -> Self {
+    Self {
+      stream,
+      complete: false,
+    }
+  }
+
+  /// Mark that no more data will come, end of stream will be reported as
+  /// usual
+  pub fn complete(self) -> Self {
+    Self {
+      complete: true,
+      ..self
+    }
+  }
+
+  /// Return true if no more data will come
+  pub const fn is_complete(&self) -> bool {
+    self.complete
+  }
+
+  /// Return the inner stream
+  pub fn into_inner(self) -> Stream {
+    self.stream
+  }
+
+  fn with_stream(self, stream: Stream) -> Self {
+    Self {
+      stream,
+      complete: self.complete,
+    }
+  }
+}
+
+impl<Stream> Partial<Stream>
+where
+  Stream: Streaming,
+{
+  // number of Item available when there is less than mid, found with a
+  // binary search so only few split_at are needed
+  fn available(&self, mid: usize) -> Result<usize, Stream::Error> {
+    // split_at(low) succeed and split_at(high) doesn't
+    let (mut low, mut high) = (0, mid);
+    while high - low > 1 {
+      let half = low + (high - low) / 2;
+      match self.stream.clone().split_at(half) {
+        Split::Success { .. } => low = half,
+        Split::NotEnoughItem(_) => high = half,
+        Split::Error(error) => return Err(error),
+      }
+    }
+    Ok(low)
+  }
+
+  fn incomplete<Item>(
+    self, needed: Option<usize>,
+  ) -> Split<Item, Self, PartialError<Stream::Error>> {
+    if self.complete {
+      Split::NotEnoughItem(self)
+    } else {
+      Split::Error(PartialError::Incomplete { needed })
+    }
+  }
+}
+
+impl<Stream> Streaming for Partial<Stream>
+where
+  Stream: Streaming,
+{
+  type Error = PartialError<Stream::Error>;
+  type Item = Stream::Item;
+  type Span = Stream::Span;
+
+  fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
+    match self.stream.clone().split_first() {
+      Split::Success { item, stream } => Split::Success {
+        item,
+        stream: self.with_stream(stream),
+      },
+      Split::NotEnoughItem(_) => self.incomplete(Some(1)),
+      Split::Error(error) => Split::Error(PartialError::Stream(error)),
+    }
+  }
+
+  fn split_at(self, mid: usize) -> Split<Self::Span, Self, Self::Error> {
+    match self.stream.clone().split_at(mid) {
+      Split::Success { item, stream } => Split::Success {
+        item,
+        stream: self.with_stream(stream),
+      },
+      Split::NotEnoughItem(_) if self.complete => Split::NotEnoughItem(self),
+      Split::NotEnoughItem(_) => match self.available(mid) {
+        Ok(available) => Split::Error(PartialError::Incomplete {
+          needed: Some(mid - available),
+        }),
+        Err(error) => Split::Error(PartialError::Stream(error)),
+      },
+      Split::Error(error) => Split::Error(PartialError::Stream(error)),
+    }
+  }
+
+  fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
+    if !self.complete {
+      return self.incomplete(None);
+    }
+
+    match self.stream.clone().split_last() {
+      Split::Success { item, stream } => Split::Success {
+        item,
+        stream: self.with_stream(stream),
+      },
+      Split::NotEnoughItem(_) => Split::NotEnoughItem(self),
+      Split::Error(error) => Split::Error(PartialError::Stream(error)),
+    }
+  }
+
+  fn all(self) -> Result<Success<Self::Span, Self>, Self::Error> {
+    if !self.complete {
+      return Err(PartialError::Incomplete { needed: None });
+    }
+
+    let Success { token, stream } = self.stream.clone().all().map_err(PartialError::Stream)?;
+    Ok(Success {
+      token,
+      stream: self.with_stream(stream),
+    })
+  }
+
+  fn diff(self, other: &Self) -> Result<Self::Span, Self> {
+    match self.stream.clone().diff(&other.stream) {
+      Ok(span) => Ok(span),
+      Err(_) => Err(self),
+    }
+  }
+
+  fn require(&self, n: usize) -> Result<(), Self::Error> {
+    if self.complete {
+      return Ok(());
+    }
+
+    match self.stream.clone().split_at(n) {
+      Split::Success { .. } => Ok(()),
+      Split::NotEnoughItem(_) => Err(PartialError::Incomplete {
+        needed: Some(n - self.available(n).map_err(PartialError::Stream)?),
+      }),
+      Split::Error(error) => Err(PartialError::Stream(error)),
+    }
+  }
+
+  fn cursor(&self) -> Option<usize> {
+    self.stream.cursor()
+  }
+}
+
+/// Offset is the offset of the inner stream
+impl<Stream> StreamPosition for Partial<Stream>
+where
+  Stream: StreamPosition,
+{
+  fn offset(&self) -> usize {
+    self.stream.offset()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use super::{
+    Partial,
+    PartialError,
+  };
+  use crate::{
+    base::{
+      is,
+      primitive::u32_be,
+      tag,
+      take,
+      BaseAtom,
+    },
+    context::{
+      First,
+      Ignore,
+      Keep,
+      Last,
+    },
+    utils::{
+      Utils,
+      UtilsAtom,
+    },
+    CoreAtom,
+    Parse,
+    Parsed,
+    ProvideElement,
+    Split,
+    Streaming,
+  };
+
+  type Stream = Partial<&'static [u8]>;
+
+  #[derive(Display, Debug, From, PartialEq)]
+  enum FromAtom {
+    Utils(UtilsAtom<Stream>),
+    Base(BaseAtom<u8>),
+    Core(CoreAtom<Stream>),
+  }
+
+  // the first atom is the one produced from the stream error
+  fn needed<Token>(parsed: Parsed<Token, Stream, Keep<First, FromAtom>>) -> Option<usize> {
+    match parsed {
+      Parsed::Error(context) => match context.last() {
+        FromAtom::Core(CoreAtom::Error { error }) => error.needed(),
+        _ => None,
+      },
+      _ => None,
+    }
+  }
+
+  #[test]
+  fn split() {
+    let stream = Partial::new(&b"ab"[..]);
+    assert_eq!(
+      stream.clone().split_at(5),
+      Split::Error(PartialError::Incomplete { needed: Some(3) })
+    );
+    assert_eq!(
+      stream.clone().split_last(),
+      Split::Error(PartialError::Incomplete { needed: None })
+    );
+    let Split::Success { stream, .. } = stream.split_at(2) else {
+      panic!()
+    };
+    assert_eq!(
+      stream.clone().split_first(),
+      Split::Error(PartialError::Incomplete { needed: Some(1) })
+    );
+    let stream = stream.complete();
+    assert_eq!(stream.clone().split_first(), Split::NotEnoughItem(stream));
+  }
+
+  #[test]
+  fn needed_exact() {
+    let stream = Partial::new(&b"le"[..]);
+    assert_eq!(needed(tag("let").parse(stream.clone())), Some(1));
+    assert_eq!(needed(take(6).parse(stream.clone())), Some(4));
+    assert_eq!(needed(take(1000).parse(stream.clone())), Some(998));
+    // octet of the tag, not char
+    assert_eq!(needed(tag("l❤").parse(stream.clone())), Some(2));
+    assert_eq!(needed(u32_be.parse(stream.clone())), Some(2));
+    assert_eq!(needed(u32_be.parse(stream.complete())), None);
+  }
+
+  #[test]
+  fn fold_bounds() {
+    let stream = Partial::new(&b"aa"[..]);
+    let parsed: Parsed<_, _, Keep<Last, FromAtom>> =
+      is(b'a').fold_bounds(5.., || (), |_, _| ()).parse(stream);
+    let Parsed::Error(context) = parsed else {
+      panic!()
+    };
+    assert_eq!(
+      context.last(),
+      &FromAtom::Utils(UtilsAtom::MinNotReach { i: 2, min: 5 })
+    );
+
+    // Error that doesn't come from the end of the stream
+    let stream = Partial::new(&b"abac"[..]);
+    let parsed: Parsed<_, _, Keep<Last, FromAtom>> = is(b'a')
+      .and(is(b'b').cut())
+      .fold_bounds(5.., || (), |_, _| ())
+      .parse(stream);
+    let Parsed::Error(context) = parsed else {
+      panic!()
+    };
+    assert_eq!(
+      context.last(),
+      &FromAtom::Base(BaseAtom::Is {
+        t: Some(b'c'),
+        expect: b'b',
+      })
+    );
+  }
+
+  #[test]
+  fn complete() {
+    let stream = Partial::new(&b"let"[..]);
+    let parsed: Parsed<_, _, Ignore> = tag("let").parse(stream);
+    let parsed = parsed.unwrap();
+    assert_eq!(parsed.token, "let");
+    assert!(!parsed.stream.is_complete());
+  }
+}
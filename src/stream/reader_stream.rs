@@ -2,72 +2,94 @@ use alloc::rc::Rc;
 use core::{
   cell::UnsafeCell,
   fmt::Debug,
+  ops::Range,
   slice::from_raw_parts_mut,
 };
 use std::io::{
   self,
+  ErrorKind,
   Read,
 };
 
 use crate::{
-  stream::Position,
+  stream::{
+    Position,
+    VecStream,
+  },
   Split,
+  StreamPosition,
   Streaming,
   Success,
 };
 
+// buf is shared with the Span, it's only copied if a read happen while a
+// Span is alive
 #[derive(Debug)]
 struct Buf<Reader: Read, const N: usize> {
-  buf: Vec<u8>,
+  buf: Rc<Vec<u8>>,
   reader: Reader,
 }
 
 impl<Reader: Read, const N: usize> Buf<Reader, N> {
   fn new(reader: Reader) -> Self {
     Self {
-      buf: Vec::new(),
+      buf: Rc::new(Vec::new()),
       reader,
     }
   }
 
   fn read_all(&mut self) -> Result<usize, io::Error> {
-    while self.read()? == 0 {}
+    while self.read()? != 0 {}
     Ok(self.buf.len())
   }
 
   fn read(&mut self) -> Result<usize, io::Error> {
-    unsafe {
-      self.buf.reserve(N);
-      let len = self.buf.len();
-      let ptr = self.buf.as_mut_ptr();
-      match self.reader.read(from_raw_parts_mut(ptr.add(len), N)) {
-        Ok(n) => {
-          self.buf.set_len(len + n);
-          Ok(n)
+    loop {
+      unsafe {
+        let buf = Rc::make_mut(&mut self.buf);
+        buf.reserve(N);
+        let len = buf.len();
+        let ptr = buf.as_mut_ptr();
+        match self.reader.read(from_raw_parts_mut(ptr.add(len), N)) {
+          Ok(n) => {
+            buf.set_len(len + n);
+            break Ok(n);
+          }
+          Err(error) if error.kind() == ErrorKind::Interrupted => {}
+          Err(error) => break Err(error),
         }
-        Err(error) => Err(error),
       }
     }
   }
 
-  fn get(&mut self, i: usize) -> Option<Result<u8, io::Error>> {
-    loop {
-      if let Some(o) = self.buf.get(i) {
-        break Some(Ok(*o));
-      }
-      match self.read() {
-        Ok(n) => {
-          if n == 0 {
-            break None;
-          }
-        }
-        Err(error) => break Some(Err(error)),
+  /// Read until buf contains at least len octet, return false if reader reach
+  /// end of file before
+  fn fill(&mut self, len: usize) -> Result<bool, io::Error> {
+    while self.buf.len() < len {
+      if self.read()? == 0 {
+        return Ok(false);
       }
     }
+    Ok(true)
+  }
+
+  fn get(&mut self, i: usize) -> Option<Result<u8, io::Error>> {
+    match self.fill(i + 1) {
+      Ok(true) => Some(Ok(self.buf[i])),
+      Ok(false) => None,
+      Err(error) => Some(Err(error)),
+    }
   }
 }
 
 /// Stream that will read grow as needed by reading into a Reader.
+/// Data are only read when a parser need them, `N` is the number of
+/// octet asked to the Reader for each read.
+///
+/// The Span is a [VecStream] that share the data read, and no longer the
+/// ReaderStream itself, so it can implement `AsRef<[u8]>`. A Span doesn't
+/// copy anything but if the stream need to read more while a Span is alive
+/// the buffer is copied once to not change the data of the Span.
 #[derive(Debug)]
 pub struct ReaderStream<Reader: Read, const N: usize> {
   buf: Rc<UnsafeCell<Buf<Reader, N>>>,
@@ -91,12 +113,25 @@ impl<Reader: Read, const N: usize> ReaderStream<Reader, N> {
       position: Position::RangeFrom(0..),
     }
   }
+
+  fn with_position(self, position: Position) -> Self {
+    Self {
+      buf: self.buf,
+      position,
+    }
+  }
+
+  // range must be already read
+  fn span(&self, range: Range<usize>) -> VecStream {
+    let buf = unsafe { &*self.buf.get() };
+    VecStream::from_rc(Rc::clone(&buf.buf), range)
+  }
 }
 
 impl<Reader: Read + Debug, const N: usize> Streaming for ReaderStream<Reader, N> {
   type Error = io::Error;
   type Item = u8;
-  type Span = Self;
+  type Span = VecStream;
 
   fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
     unsafe {
@@ -104,10 +139,7 @@ impl<Reader: Read + Debug, const N: usize> Streaming for ReaderStream<Reader, N>
       match i.and_then(|i| (*self.buf.get()).get(i)) {
         Some(Ok(o)) => Split::Success {
           item: o,
-          stream: Self {
-            buf: self.buf,
-            position,
-          },
+          stream: self.with_position(position),
         },
         None => Split::NotEnoughItem(self),
         Some(Err(error)) => Split::Error(error),
@@ -116,61 +148,84 @@ impl<Reader: Read + Debug, const N: usize> Streaming for ReaderStream<Reader, N>
   }
 
   fn all(self) -> Result<Success<Self::Span, Self>, Self::Error> {
-    unsafe {
-      match self.position {
-        Position::RangeFrom(range) => {
-          let ret = (*self.buf.get()).read_all();
-          match ret {
-            Ok(end) => {
-              let empty = Self {
-                buf: self.buf.clone(),
-                position: Position::Range(end..end),
-              };
-              Ok(Success {
-                token: Self {
-                  buf: self.buf,
-                  position: Position::Range(range.start..end),
-                },
-                stream: empty,
-              })
-            }
-            Err(error) => Err(error),
-          }
-        }
-        Position::Range(range) => {
-          let empty = Self {
-            buf: self.buf.clone(),
-            position: Position::Range(range.end..range.end),
-          };
-          Ok(Success {
-            token: Self {
-              buf: self.buf,
-              position: Position::Range(range),
-            },
-            stream: empty,
-          })
-        }
-      }
-    }
+    let range = match &self.position {
+      Position::RangeFrom(from) => from.start..unsafe { (*self.buf.get()).read_all()? },
+      Position::Range(range) => range.clone(),
+    };
+
+    Ok(Success {
+      token: self.span(range.clone()),
+      stream: self.with_position(Position::Range(range.end..range.end)),
+    })
   }
 
-  fn split_at(self, _mid: usize) -> Split<Self::Span, Self, Self::Error> {
-    todo!()
+  fn split_at(self, mid: usize) -> Split<Self::Span, Self, Self::Error> {
+    let start = self.position.start();
+    let mid = match start.checked_add(mid) {
+      Some(mid) => mid,
+      None => return Split::NotEnoughItem(self),
+    };
+
+    let position = match &self.position {
+      Position::RangeFrom(_) => match unsafe { (*self.buf.get()).fill(mid) } {
+        Ok(true) => Position::RangeFrom(mid..),
+        Ok(false) => return Split::NotEnoughItem(self),
+        Err(error) => return Split::Error(error),
+      },
+      Position::Range(range) if mid <= range.end => Position::Range(mid..range.end),
+      Position::Range(_) => return Split::NotEnoughItem(self),
+    };
+
+    Split::Success {
+      item: self.span(start..mid),
+      stream: self.with_position(position),
+    }
   }
 
   fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
-    todo!()
+    let start = self.position.start();
+    let end = match &self.position {
+      Position::RangeFrom(_) => match unsafe { (*self.buf.get()).read_all() } {
+        Ok(end) => end,
+        Err(error) => return Split::Error(error),
+      },
+      Position::Range(range) => range.end,
+    };
+
+    if start < end {
+      let last = end - 1;
+      let buf = unsafe { &*self.buf.get() };
+      Split::Success {
+        item: buf.buf[last],
+        stream: self.with_position(Position::Range(start..last)),
+      }
+    } else {
+      Split::NotEnoughItem(self)
+    }
   }
 
   fn diff(self, other: &Self) -> Result<Self::Span, Self> {
-    match self.position.range(&other.position) {
-      Some(range) => Ok(Self {
-        buf: self.buf,
-        position: Position::Range(range),
-      }),
+    // a Range is only created once all was read
+    let len = unsafe { (*self.buf.get()).buf.len() };
+    match Rc::ptr_eq(&self.buf, &other.buf)
+      .then(|| self.position.range(&other.position, len))
+      .flatten()
+    {
+      Some(range) => Ok(self.span(range)),
       None => Err(self),
     }
   }
+
+  fn cursor(&self) -> Option<usize> {
+    Some(self.position.start())
+  }
+}
+
+/// Offset is the number of octet before the cursor
+impl<Reader: Read + Debug, const N: usize> StreamPosition for ReaderStream<Reader, N> {
+  fn offset(&self) -> usize {
+    self.position.start()
+  }
 }
 
 impl<Reader: Read, const N: usize> PartialEq for ReaderStream<Reader, N> {
@@ -180,3 +235,157 @@ impl<Reader: Read, const N: usize> PartialEq for ReaderStream<Reader, N> {
 }
 
 impl<Reader: Read, const N: usize> Eq for ReaderStream<Reader, N> {}
+
+#[cfg(test)]
+mod tests {
+  use std::io::{
+    self,
+    Cursor,
+    Read,
+  };
+
+  use super::ReaderStream;
+  use crate::{
+    base::{
+      octet,
+      tag,
+      take,
+    },
+    context::{
+      Ignore,
+      Keep,
+      Last,
+    },
+    utils::Utils,
+    CoreAtom,
+    Parse,
+    Parsed,
+    ProvideElement,
+    Split,
+    Streaming,
+    Success,
+  };
+
+  type Stream = ReaderStream<Cursor<&'static [u8]>, 2>;
+
+  fn stream() -> Stream {
+    ReaderStream::new(Cursor::new(&b"abcdefg"[..]))
+  }
+
+  #[derive(Debug)]
+  struct Broken;
+
+  impl Read for Broken {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+      Err(io::Error::other("broken"))
+    }
+  }
+
+  #[test]
+  fn split_first() {
+    let Split::Success { item, stream } = stream().split_first() else {
+      panic!()
+    };
+    assert_eq!(item, b'a');
+    let Success { token, .. } = stream.all().unwrap();
+    assert_eq!(token.as_ref(), b"bcdefg");
+
+    let stream: Stream = ReaderStream::new(Cursor::new(&b""[..]));
+    assert!(matches!(
+      stream.clone().split_first(),
+      Split::NotEnoughItem(not_enough) if not_enough == stream
+    ));
+  }
+
+  #[test]
+  fn split_at() {
+    let slice = b"abcdefg";
+    for n in 0..=slice.len() {
+      let Split::Success { item, stream } = stream().split_at(n) else {
+        panic!()
+      };
+      assert_eq!(item.as_ref(), &slice[..n]);
+      let Success { token, .. } = stream.all().unwrap();
+      assert_eq!(token.as_ref(), &slice[n..]);
+    }
+
+    let stream = stream();
+    assert!(matches!(
+      stream.clone().split_at(slice.len() + 1),
+      Split::NotEnoughItem(not_enough) if not_enough == stream
+    ));
+  }
+
+  #[test]
+  fn split_last() {
+    let Split::Success { item, stream } = stream().split_last() else {
+      panic!()
+    };
+    assert_eq!(item, b'g');
+    let Split::Success { item, stream } = stream.split_last() else {
+      panic!()
+    };
+    assert_eq!(item, b'f');
+    let Success { token, .. } = stream.all().unwrap();
+    assert_eq!(token.as_ref(), b"abcde");
+  }
+
+  #[test]
+  fn diff() {
+    let stream = stream();
+    let Split::Success { stream: mid, .. } = stream.clone().split_at(3) else {
+      panic!()
+    };
+    assert_eq!(stream.clone().diff(&mid).unwrap().as_ref(), b"abc");
+    assert!(mid.clone().diff(&stream).is_err());
+    assert!(stream.clone().diff(&self::stream()).is_err());
+
+    let Success { stream: end, .. } = mid.clone().all().unwrap();
+    assert_eq!(mid.clone().diff(&end).unwrap().as_ref(), b"defg");
+    // the stream is not at the end so it's not a suffix of mid
+    let Split::Success { stream: last, .. } = mid.clone().split_last() else {
+      panic!()
+    };
+    assert!(mid.diff(&last).is_err());
+  }
+
+  #[test]
+  fn shared_span() {
+    let stream = stream();
+    let Split::Success { item: a, .. } = stream.clone().split_at(3) else {
+      panic!()
+    };
+    let Split::Success { item: b, .. } = stream.clone().split_at(2) else {
+      panic!()
+    };
+    assert_eq!(a.as_ref().as_ptr(), b.as_ref().as_ptr());
+    // read more while a is alive doesn't change it
+    let Success { token, .. } = stream.all().unwrap();
+    assert_eq!(token.as_ref(), b"abcdefg");
+    assert_eq!(a.as_ref(), b"abc");
+  }
+
+  #[test]
+  fn base() {
+    let parsed: Parsed<_, _, Ignore> = tag("abc").and(take(2)).span().and(octet).parse(stream());
+    let Success {
+      token: (span, octet),
+      stream,
+    } = parsed.unwrap();
+    assert_eq!(span.token.1.as_ref(), b"de");
+    assert_eq!(span.stream.as_ref(), b"abcde");
+    assert_eq!(octet, b'f');
+    let Success { token, .. } = stream.all().unwrap();
+    assert_eq!(token.as_ref(), b"g");
+  }
+
+  #[test]
+  fn error() {
+    let stream = ReaderStream::<_, 2>::new(Broken);
+    let parsed: Parsed<_, _, Keep<Last, CoreAtom<_>>> = octet.parse(stream);
+    let Parsed::Error(context) = parsed else {
+      panic!()
+    };
+    assert!(matches!(context.last(), CoreAtom::Error { .. }));
+  }
+}
@@ -0,0 +1,287 @@
+use core::fmt::Debug;
+
+use crate::{
+  Parse,
+  Parsed,
+  Split,
+  StreamPosition,
+  Streaming,
+  Success,
+};
+
+/// A stream that carry a user state along the inner stream.
+/// State is cloned with the stream, so when a parser backtrack
+/// the state is restored. Span are the Span of the inner stream.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stateful<Stream, State> {
+  stream: Stream,
+  state: State,
+}
+
+impl<Stream, State> Stateful<Stream, State> {
+  /// Return a new Stateful from a stream and an initial state
+  pub const fn new(stream: Stream, state: State) -> Self {
+    Self { stream, state }
+  }
+
+  /// Return a reference to the state
+  pub const fn state(&self) -> &State {
+    &self.state
+  }
+
+  /// Return a mutable reference to the state
+  pub const fn state_mut(&mut self) -> &mut State {
+    &mut self.state
+  }
+
+  /// Return the inner stream and the state
+  pub fn into_inner(self) -> (Stream, State) {
+    (self.stream, self.state)
+  }
+}
+
+impl<Stream, State> Streaming for Stateful<Stream, State>
+where
+  Stream: Streaming,
+  State: Clone + Eq + Debug,
+{
+  type Error = Stream::Error;
+  type Item = Stream::Item;
+  type Span = Stream::Span;
+
+  fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
+    match self.stream.split_first() {
+      Split::Success { item, stream } => Split::Success {
+        item,
+        stream: Self {
+          stream,
+          state: self.state,
+        },
+      },
+      Split::NotEnoughItem(stream) => Split::NotEnoughItem(Self {
+        stream,
+        state: self.state,
+      }),
+      Split::Error(error) => Split::Error(error),
+    }
+  }
+
+  fn split_at(self, mid: usize) -> Split<Self::Span, Self, Self::Error> {
+    match self.stream.split_at(mid) {
+      Split::Success { item, stream } => Split::Success {
+        item,
+        stream: Self {
+          stream,
+          state: self.state,
+        },
+      },
+      Split::NotEnoughItem(stream) => Split::NotEnoughItem(Self {
+        stream,
+        state: self.state,
+      }),
+      Split::Error(error) => Split::Error(error),
+    }
+  }
+
+  fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
+    match self.stream.split_last() {
+      Split::Success { item, stream } => Split::Success {
+        item,
+        stream: Self {
+          stream,
+          state: self.state,
+        },
+      },
+      Split::NotEnoughItem(stream) => Split::NotEnoughItem(Self {
+        stream,
+        state: self.state,
+      }),
+      Split::Error(error) => Split::Error(error),
+    }
+  }
+
+  fn all(self) -> Result<Success<Self::Span, Self>, Self::Error> {
+    let Success { token, stream } = self.stream.all()?;
+    Ok(Success {
+      token,
+      stream: Self {
+        stream,
+        state: self.state,
+      },
+    })
+  }
+
+  fn diff(self, other: &Self) -> Result<Self::Span, Self> {
+    self.stream.diff(&other.stream).map_err(|stream| Self {
+      stream,
+      state: self.state,
+    })
+  }
+
+  fn require(&self, n: usize) -> Result<(), Self::Error> {
+    self.stream.require(n)
+  }
+
+  fn cursor(&self) -> Option<usize> {
+    self.stream.cursor()
+  }
+}
+
+/// Offset is the offset of the inner stream, the state is not part of it
+impl<Stream, State> StreamPosition for Stateful<Stream, State>
+where
+  Stream: StreamPosition,
+  State: Clone + Eq + Debug,
+{
+  fn offset(&self) -> usize {
+    self.stream.offset()
+  }
+}
+
+/// Implementation of [with_state]
+#[derive(Clone)]
+pub struct WithState<F> {
+  f: F,
+}
+
+impl<Stream, State, Context, F, Parser> Parse<Stateful<Stream, State>, Context> for WithState<F>
+where
+  Stateful<Stream, State>: Streaming,
+  F: FnMut(&State) -> Parser,
+  Parser: Parse<Stateful<Stream, State>, Context>,
+{
+  type Token = Parser::Token;
+
+  fn parse(
+    &mut self, stream: Stateful<Stream, State>,
+  ) -> Parsed<Self::Token, Stateful<Stream, State>, Context> {
+    (self.f)(stream.state()).parse(stream)
+  }
+}
+
+/// Call f with the current state to build the parser to use,
+/// this allow parser to depend of the state.
+pub const fn with_state<Stream, State, Context, F, Parser>(f: F) -> WithState<F>
+where
+  Stateful<Stream, State>: Streaming,
+  F: FnMut(&State) -> Parser,
+  Parser: Parse<Stateful<Stream, State>, Context>,
+{
+  WithState { f }
+}
+
+/// Implementation of [update_state]
+#[derive(Clone)]
+pub struct UpdateState<Parser, F> {
+  parser: Parser,
+  f: F,
+}
+
+impl<Stream, State, Context, Parser, F> Parse<Stateful<Stream, State>, Context>
+  for UpdateState<Parser, F>
+where
+  Stateful<Stream, State>: Streaming,
+  Parser: Parse<Stateful<Stream, State>, Context>,
+  F: FnMut(&mut State, &Parser::Token),
+{
+  type Token = Parser::Token;
+
+  fn parse(
+    &mut self, stream: Stateful<Stream, State>,
+  ) -> Parsed<Self::Token, Stateful<Stream, State>, Context> {
+    let Success { token, mut stream } = self.parser.parse(stream)?;
+    (self.f)(stream.state_mut(), &token);
+    Parsed::Success { token, stream }
+  }
+}
+
+/// If parser is successful f is called with the state and the token
+/// produced so f can change the state.
+pub const fn update_state<Stream, State, Context, Parser, F>(
+  parser: Parser, f: F,
+) -> UpdateState<Parser, F>
+where
+  Stateful<Stream, State>: Streaming,
+  Parser: Parse<Stateful<Stream, State>, Context>,
+  F: FnMut(&mut State, &Parser::Token),
+{
+  UpdateState { parser, f }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{
+    update_state,
+    with_state,
+    Stateful,
+  };
+  use crate::{
+    base::{
+      is,
+      octet,
+      tag,
+    },
+    context::Ignore,
+    utils::Utils,
+    Parse,
+    Parsed,
+    Split,
+    Streaming,
+    Success,
+  };
+
+  #[test]
+  fn streaming() {
+    let stream = Stateful::new(&b"abc"[..], 42_u32);
+    let Split::Success { item, stream } = stream.split_first() else {
+      panic!()
+    };
+    assert_eq!(item, b'a');
+    assert_eq!(stream.state(), &42);
+    let Split::Success { item, stream } = stream.split_last() else {
+      panic!()
+    };
+    assert_eq!(item, b'c');
+    assert_eq!(stream.into_inner(), (&b"b"[..], 42));
+  }
+
+  #[test]
+  fn update() {
+    let stream = Stateful::new(&b"aab"[..], 0);
+    let parsed: Parsed<_, _, Ignore> =
+      update_state(is(b'a'), |count: &mut usize, _: &u8| *count += 1)
+        .fold_bounds(.., || (), |_, _| ())
+        .parse(stream);
+    let Success { stream, .. } = parsed.unwrap();
+    assert_eq!(stream.into_inner(), (&b"b"[..], 2));
+  }
+
+  #[test]
+  fn backtrack() {
+    let stream = Stateful::new(&b"ab"[..], 0);
+    let parsed: Parsed<_, _, Ignore> =
+      update_state(tag("a"), |count: &mut usize, _: &&str| *count += 1)
+        .and(is(b'c'))
+        .drop()
+        .or(octet.drop())
+        .parse(stream);
+    let Success { stream, .. } = parsed.unwrap();
+    assert_eq!(stream.into_inner(), (&b"b"[..], 0));
+  }
+
+  #[test]
+  fn read() {
+    let stream = Stateful::new(&b"xyz"[..], b'x');
+    let parsed: Parsed<_, _, Ignore> = with_state(|&octet: &u8| is(octet))
+      .and(with_state(|&octet: &u8| is(octet)))
+      .parse(stream.clone());
+    assert_eq!(parsed, Parsed::Failure(Ignore));
+    let parsed: Parsed<_, _, Ignore> =
+      update_state(octet, |state: &mut u8, &octet: &u8| *state = octet + 1)
+        .and(with_state(|&octet: &u8| is(octet)))
+        .parse(stream);
+    let Success { token, stream } = parsed.unwrap();
+    assert_eq!(token, (b'x', b'y'));
+    assert_eq!(stream.into_inner(), (&b"z"[..], b'y'));
+  }
+}
@@ -10,10 +10,15 @@ use core::{
 
 use crate::{
   Split,
+  StreamPosition,
   Streaming,
   Success,
 };
 
+/// A stream that return char from a `String`
+/// This can be used if you want give ownership of data to
+/// the stream. Span are cheap clone of the stream that give
+/// access to a `&str`
 #[derive(Debug, PartialEq, Eq)]
 pub struct StringStream {
   string: Rc<String>,
@@ -21,12 +26,24 @@ pub struct StringStream {
 }
 
 impl StringStream {
+  /// Return a new `StringStream` from a `String`
   pub fn new(string: String) -> Self {
     Self {
       range: 0..string.len(),
       string: Rc::new(string),
     }
   }
+
+  /// Return the `&str` view of the stream
+  pub fn as_str(&self) -> &str {
+    &self.string[self.range.clone()]
+  }
+}
+
+impl From<String> for StringStream {
+  fn from(string: String) -> Self {
+    Self::new(string)
+  }
 }
 
 impl Clone for StringStream {
@@ -38,9 +55,15 @@ impl Clone for StringStream {
   }
 }
 
+impl AsRef<str> for StringStream {
+  fn as_ref(&self) -> &str {
+    self.as_str()
+  }
+}
+
 impl AsRef<[u8]> for StringStream {
   fn as_ref(&self) -> &[u8] {
-    &self.string.as_bytes()[self.range.clone()]
+    self.as_str().as_bytes()
   }
 }
 
@@ -50,13 +73,11 @@ impl Streaming for StringStream {
   type Span = Self;
 
   fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
-    let stream = &self.string[self.range.clone()];
-    let mut iter = stream.chars();
-    match iter.next() {
+    match self.as_str().chars().next() {
       Some(c) => Split::Success {
         item: c,
         stream: Self {
-          range: self.range.end + (stream.len() - iter.as_str().len())..self.range.end,
+          range: self.range.start + c.len_utf8()..self.range.end,
           string: self.string,
         },
       },
@@ -75,22 +96,168 @@ impl Streaming for StringStream {
     })
   }
 
-  fn split_at(self, _mid: usize) -> Split<Self, Self, Self::Error> {
-    todo!()
+  fn split_at(self, mid: usize) -> Split<Self, Self, Self::Error> {
+    match Streaming::split_at(self.as_str(), mid) {
+      Split::Success { item, .. } => {
+        let mid = self.range.start + item.len();
+        Split::Success {
+          item: Self {
+            string: self.string.clone(),
+            range: self.range.start..mid,
+          },
+          stream: Self {
+            string: self.string,
+            range: mid..self.range.end,
+          },
+        }
+      }
+      Split::NotEnoughItem(_) => Split::NotEnoughItem(self),
+      Split::Error(error) => match error {},
+    }
   }
 
   fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
-    todo!()
+    match self.as_str().chars().next_back() {
+      Some(c) => Split::Success {
+        item: c,
+        stream: Self {
+          range: self.range.start..self.range.end - c.len_utf8(),
+          string: self.string,
+        },
+      },
+      None => Split::NotEnoughItem(self),
+    }
   }
 
   fn diff(self, other: &Self) -> Result<Self::Span, Self> {
-    if self.range.start >= other.range.start {
+    if Rc::ptr_eq(&self.string, &other.string)
+      && self.range.start <= other.range.start
+      && self.range.end == other.range.end
+    {
       Ok(Self {
+        range: self.range.start..other.range.start,
         string: self.string,
-        range: self.range.start..self.range.start - other.range.start,
       })
     } else {
       Err(self)
     }
   }
+
+  fn cursor(&self) -> Option<usize> {
+    Some(self.range.start)
+  }
+}
+
+/// Offset is the start of the range of the String
+impl StreamPosition for StringStream {
+  fn offset(&self) -> usize {
+    self.range.start
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::string::String;
+
+  use super::StringStream;
+  use crate::{
+    base::{
+      is,
+      tag,
+    },
+    context::Ignore,
+    utils::Utils,
+    Parse,
+    Split,
+    Streaming,
+    Success,
+  };
+
+  fn stream() -> StringStream {
+    StringStream::new(String::from("a❤cé"))
+  }
+
+  #[test]
+  fn split_first() {
+    let stream = stream();
+    let Split::Success { item, stream } = stream.split_first() else {
+      panic!()
+    };
+    assert_eq!(item, 'a');
+    let Split::Success { item, stream } = stream.split_first() else {
+      panic!()
+    };
+    assert_eq!(item, '❤');
+    assert_eq!(stream.as_str(), "cé");
+
+    let stream = StringStream::new(String::new());
+    assert_eq!(stream.clone().split_first(), Split::NotEnoughItem(stream));
+  }
+
+  #[test]
+  fn split_at() {
+    let stream = stream();
+    let string = "a❤cé";
+    let boundaries = [0, 1, 4, 5, 7];
+    for (n, &i) in boundaries.iter().enumerate() {
+      let Split::Success { item, stream } = stream.clone().split_at(n) else {
+        panic!()
+      };
+      assert_eq!(item.as_str(), &string[..i]);
+      assert_eq!(stream.as_str(), &string[i..]);
+    }
+
+    let n = boundaries.len();
+    assert_eq!(
+      stream.clone().split_at(n),
+      Split::NotEnoughItem(stream.clone())
+    );
+  }
+
+  #[test]
+  fn split_last() {
+    let stream = stream();
+    let Split::Success { item, stream } = stream.split_last() else {
+      panic!()
+    };
+    assert_eq!(item, 'é');
+    assert_eq!(stream.as_str(), "a❤c");
+
+    let stream = StringStream::new(String::new());
+    assert_eq!(stream.clone().split_last(), Split::NotEnoughItem(stream));
+  }
+
+  #[test]
+  fn all() {
+    let stream = stream();
+    let Success { token, stream } = stream.all().unwrap();
+    assert_eq!(token.as_str(), "a❤cé");
+    assert_eq!(stream.as_str(), "");
+  }
+
+  #[test]
+  fn diff() {
+    let stream = stream();
+    let Split::Success { stream: mid, .. } = stream.clone().split_at(2) else {
+      panic!()
+    };
+    assert_eq!(stream.clone().diff(&mid).unwrap().as_str(), "a❤");
+    assert_eq!(mid.clone().diff(&stream), Err(mid));
+
+    let other = self::stream();
+    assert_eq!(stream.clone().diff(&other), Err(stream));
+  }
+
+  #[test]
+  fn base() {
+    let stream = StringStream::new(String::from("let x"));
+    let Success { token, stream } = tag::<_, Ignore>("let")
+      .and(is(' '))
+      .span()
+      .parse(stream)
+      .unwrap();
+    assert_eq!(token.token, ("let", ' '));
+    assert_eq!(token.stream.as_str(), "let ");
+    assert_eq!(stream.as_str(), "x");
+  }
 }
@@ -7,6 +7,7 @@ use core::{
 
 use crate::{
   Split,
+  StreamPosition,
   Streaming,
   Success,
 };
@@ -14,24 +15,30 @@ use crate::{
 /// A stream that return Data from a `Vec<u8>`
 /// This can be used if you want give ownership of data to
 /// the stream. This allow to return Context that reference
-/// Span from Stream
-#[derive(Debug, PartialEq, Eq)]
-pub struct VecStream {
-  vec: Rc<Vec<u8>>,
+/// Span from Stream. Buf can be any owner of octet, like
+/// `MmapStream`, Span share it.
+#[derive(Debug)]
+pub struct VecStream<Buf = Vec<u8>> {
+  vec: Rc<Buf>,
   range: Range<usize>,
 }
 
-impl VecStream {
-  /// Return a new `VecStream` from a `Vec<u8>`
-  pub fn new(vec: Vec<u8>) -> Self {
+impl<Buf: AsRef<[u8]>> VecStream<Buf> {
+  /// Return a new `VecStream` from a `Vec<u8>` or an other Buf
+  pub fn new(vec: Buf) -> Self {
     Self {
-      range: 0..vec.len(),
+      range: 0..vec.as_ref().len(),
       vec: Rc::new(vec),
     }
   }
+
+  // view of a shared buffer, avoid to copy it
+  pub(crate) const fn from_rc(vec: Rc<Buf>, range: Range<usize>) -> Self {
+    Self { vec, range }
+  }
 }
 
-impl Clone for VecStream {
+impl<Buf> Clone for VecStream<Buf> {
   fn clone(&self) -> Self {
     Self {
       vec: self.vec.clone(),
@@ -40,21 +47,29 @@ impl Clone for VecStream {
   }
 }
 
-impl AsRef<[u8]> for VecStream {
+impl<Buf: AsRef<[u8]>> AsRef<[u8]> for VecStream<Buf> {
   fn as_ref(&self) -> &[u8] {
-    &self.vec[self.range.clone()]
+    &(*self.vec).as_ref()[self.range.clone()]
+  }
+}
+
+/// Equal if the octets of the range are equal
+impl<Buf: AsRef<[u8]>> PartialEq for VecStream<Buf> {
+  fn eq(&self, other: &Self) -> bool {
+    self.range == other.range
+      && (Rc::ptr_eq(&self.vec, &other.vec) || (*self.vec).as_ref() == (*other.vec).as_ref())
   }
 }
 
-impl Streaming for VecStream {
+impl<Buf: AsRef<[u8]>> Eq for VecStream<Buf> {}
+
+impl<Buf: AsRef<[u8]> + Debug> Streaming for VecStream<Buf> {
   type Error = Infallible;
   type Item = u8;
   type Span = Self;
 
   fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
-    let stream = &self.vec[self.range.clone()];
-    let mut iter = stream.iter();
-    match iter.next() {
+    match self.as_ref().first() {
       Some(&o) => Split::Success {
         item: o,
         stream: Self {
@@ -77,22 +92,152 @@ impl Streaming for VecStream {
     })
   }
 
-  fn split_at(self, _mid: usize) -> Split<Self, Self, Self::Error> {
-    todo!()
+  fn split_at(self, mid: usize) -> Split<Self::Span, Self, Self::Error> {
+    if mid <= self.range.len() {
+      let mid = self.range.start + mid;
+      Split::Success {
+        item: Self {
+          vec: self.vec.clone(),
+          range: self.range.start..mid,
+        },
+        stream: Self {
+          vec: self.vec,
+          range: mid..self.range.end,
+        },
+      }
+    } else {
+      Split::NotEnoughItem(self)
+    }
   }
 
   fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
-    todo!()
+    match self.as_ref().last() {
+      Some(&o) => Split::Success {
+        item: o,
+        stream: Self {
+          range: self.range.start..self.range.end - 1,
+          vec: self.vec,
+        },
+      },
+      None => Split::NotEnoughItem(self),
+    }
   }
 
   fn diff(self, other: &Self) -> Result<Self::Span, Self> {
-    if self.range.start >= other.range.start {
+    if Rc::ptr_eq(&self.vec, &other.vec)
+      && self.range.start <= other.range.start
+      && self.range.end == other.range.end
+    {
       Ok(Self {
+        range: self.range.start..other.range.start,
         vec: self.vec,
-        range: self.range.start..self.range.start - other.range.start,
       })
     } else {
       Err(self)
     }
   }
+
+  fn cursor(&self) -> Option<usize> {
+    Some(self.range.start)
+  }
+}
+
+/// Offset is the start of the range of the Vec
+impl<Buf: AsRef<[u8]> + Debug> StreamPosition for VecStream<Buf> {
+  fn offset(&self) -> usize {
+    self.range.start
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::VecStream;
+  use crate::{
+    base::{
+      float,
+      tag,
+      take,
+    },
+    context::Ignore,
+    Parse,
+    Parsed,
+    Split,
+    Streaming,
+    Success,
+  };
+
+  fn stream() -> VecStream {
+    VecStream::new(vec![0, 1, 2, 3, 4])
+  }
+
+  #[test]
+  fn split_first() {
+    let stream = VecStream::new(b"abcd".to_vec());
+    let Split::Success { item, stream } = stream.split_first() else {
+      panic!()
+    };
+    assert_eq!(item, b'a');
+    assert_eq!(stream.as_ref(), b"bcd");
+
+    let stream = VecStream::new(Vec::new());
+    assert_eq!(stream.clone().split_first(), Split::NotEnoughItem(stream));
+  }
+
+  #[test]
+  fn split_at() {
+    let stream = stream();
+    let slice = [0, 1, 2, 3, 4];
+    for n in 0..=slice.len() {
+      let Split::Success { item, stream } = stream.clone().split_at(n) else {
+        panic!()
+      };
+      assert_eq!(item.as_ref(), &slice[..n]);
+      assert_eq!(stream.as_ref(), &slice[n..]);
+    }
+
+    let n = slice.len() + 1;
+    assert_eq!(
+      stream.clone().split_at(n),
+      Split::NotEnoughItem(stream.clone())
+    );
+  }
+
+  #[test]
+  fn split_last() {
+    let stream = VecStream::new(b"abcd".to_vec());
+    let Split::Success { item, stream } = stream.split_last() else {
+      panic!()
+    };
+    assert_eq!(item, b'd');
+    assert_eq!(stream.as_ref(), b"abc");
+
+    let stream = VecStream::new(Vec::new());
+    assert_eq!(stream.clone().split_last(), Split::NotEnoughItem(stream));
+  }
+
+  #[test]
+  fn diff() {
+    let stream = stream();
+    let Split::Success { stream: mid, .. } = stream.clone().split_at(2) else {
+      panic!()
+    };
+    assert_eq!(stream.clone().diff(&mid).unwrap().as_ref(), &[0, 1]);
+    let Success { stream: end, .. } = stream.clone().all().unwrap();
+    assert_eq!(stream.clone().diff(&end), Ok(stream.clone()));
+    assert_eq!(mid.clone().diff(&stream), Err(mid));
+
+    let other = self::stream();
+    assert_eq!(stream.clone().diff(&other), Err(stream));
+  }
+
+  #[test]
+  fn base() {
+    let stream = VecStream::new(b"abc1.5".to_vec());
+    let Success { token, stream } = take::<_, Ignore>(1).parse(stream).unwrap();
+    assert_eq!(token.as_ref(), b"a");
+    let Success { token, stream } = tag::<_, Ignore>("bc").parse(stream).unwrap();
+    assert_eq!(token, "bc");
+    let parsed: Parsed<f32, _, Ignore> = float.parse(stream);
+    assert_eq!(parsed.unwrap().token, 1.5);
+  }
 }
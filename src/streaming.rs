@@ -1,6 +1,7 @@
 use core::{
   convert::Infallible,
   fmt::Debug,
+  iter,
   ops::{
     ControlFlow,
     FromResidual,
@@ -48,9 +49,53 @@ pub trait Streaming: Sized + Clone + Eq + Debug {
     self
   }
 
+  /// Return an Error if the stream can't know yet that it contains at least
+  /// n Item, like a [crate::stream::Partial] waiting for more data. This
+  /// allow a parser to report what is missing before reading the Item one by
+  /// one. By default it does nothing, a stream that know all its Item report
+  /// its end with split.
+  fn require(&self, _n: usize) -> Result<(), Self::Error> {
+    Ok(())
+  }
+
+  /// Return the position of the cursor, None if the stream doesn't know it.
+  /// The position is in octet, or in bit for a `BitStream` that read less
+  /// than an octet at a time, only cursors of the same input can be
+  /// compared and the difference of two cursors is the number of octet
+  /// between them. Where it start depend of the stream, the address of the
+  /// first item for a slice, the number of octet already read for a stream
+  /// that own its data. A stream that wrap an other stream of the same Item
+  /// return the cursor of the inner stream, so the cursor of a
+  /// [crate::stream::Located] can be compared with the cursor of the input
+  /// it wrap. A stream that implement [StreamPosition] return its offset.
+  /// Parser give it to the Context they build so Context like
+  /// [crate::context::Furthest] can compare where failures happened.
+  fn cursor(&self) -> Option<usize> {
+    None
+  }
+
+  /// Return an iterator over the items of the stream, iteration end when
+  /// the stream doesn't have item anymore or return an error
+  fn iter(self) -> StreamIter<Self> {
+    StreamIter {
+      stream: self,
+      error: None,
+    }
+  }
+
   // fn as_octet(&self) -> &[u8];
 }
 
+/// Streaming that can tell the position of its cursor. Two streams of the
+/// same input at the same position must return the same offset and two
+/// different positions must return different offsets. Offset of different
+/// inputs are not comparable. This is used by [crate::utils::Utils::memo].
+pub trait StreamPosition: Streaming {
+  /// Return the offset of the cursor, it's the same value than
+  /// [Streaming::cursor]
+  fn offset(&self) -> usize;
+}
+
 /// Represent split Result
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Split<Item, Stream, Error> {
@@ -100,82 +145,97 @@ impl<Item, Stream, Error> Try for Split<Item, Stream, Error> {
   }
 }
 
-// impl<'a> Streaming for &'a str {
-//   type Error = Infallible;
-//   type Item = char;
-//   type Span = &'a str;
-
-//   fn split_first(self) -> SplitResult<Self::Item, Self, Self::Error> {
-//     let mut chars = self.chars();
-//     if let Some(first) = chars.next() {
-//       SplitResult::Ok {
-//         token: first,
-//         stream: chars.as_str(),
-//       }
-//     } else {
-//       SplitResult::NotEnoughItem(self)
-//     }
-//   }
-
-//   fn split_at(self, mid: usize) -> SplitResult<Self, Self, Self::Error> {
-//     if let Some((mid, _)) = self.char_indices().nth(mid) {
-//       SplitResult::Ok {
-//         token: &self[..mid],
-//         stream: &self[mid..],
-//       }
-//     } else {
-//       SplitResult::NotEnoughItem(self)
-//     }
-//   }
-
-//   fn split_last(self) -> SplitResult<Self::Item, Self, Self::Error> {
-//     let mut chars = self.chars();
-//     if let Some(c) = chars.next_back() {
-//       SplitResult::Ok {
-//         token: c,
-//         stream: chars.as_str(),
-//       }
-//     } else {
-//       SplitResult::NotEnoughItem(self)
-//     }
-//   }
-
-//   fn all(self) -> Result<(Self, Self), Self::Error> {
-//     Ok((self, &self[self.len()..]))
-//   }
-
-//   fn diff(self, other: &Self) -> Result<Self, Self> {
-//     if let Some(ret) = (self.len() >= other.len())
-//       .then(|| ..self.len() - other.len())
-//       .and_then(|range| self.get(range))
-//     {
-//       Ok(ret)
-//     } else {
-//       Err(self)
-//     }
-//   }
-
-//   fn take(self, n: usize) -> SplitResult<Self, Self, Self::Error> {
-//     if self.is_char_boundary(n) {
-//       SplitResult::Ok {
-//         token: &self[..n],
-//         stream: &self[n..],
-//       }
-//     } else {
-//       SplitResult::NotEnoughItem(self)
-//     }
-//   }
-// }
-
-impl<'a> Streaming for &'a [u8] {
+impl<'a> Streaming for &'a str {
+  type Error = Infallible;
+  type Item = char;
+  type Span = &'a str;
+
+  fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
+    let mut chars = self.chars();
+    if let Some(first) = chars.next() {
+      Split::Success {
+        item: first,
+        stream: chars.as_str(),
+      }
+    } else {
+      Split::NotEnoughItem(self)
+    }
+  }
+
+  fn split_at(self, mid: usize) -> Split<Self, Self, Self::Error> {
+    // mid is a number of char, the end of the str is a valid boundary too
+    if let Some(mid) = self
+      .char_indices()
+      .map(|(i, _)| i)
+      .chain(iter::once(self.len()))
+      .nth(mid)
+    {
+      let (head, tail) = str::split_at(self, mid);
+      Split::Success {
+        item: head,
+        stream: tail,
+      }
+    } else {
+      Split::NotEnoughItem(self)
+    }
+  }
+
+  fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
+    let mut chars = self.chars();
+    if let Some(last) = chars.next_back() {
+      Split::Success {
+        item: last,
+        stream: chars.as_str(),
+      }
+    } else {
+      Split::NotEnoughItem(self)
+    }
+  }
+
+  fn all(self) -> Result<Success<Self::Span, Self>, Self::Error> {
+    Ok(Success {
+      token: self,
+      stream: &self[self.len()..],
+    })
+  }
+
+  fn diff(self, other: &Self) -> Result<Self, Self> {
+    if let Some(ret) = self
+      .len()
+      .checked_sub(other.len())
+      .filter(|&offset| self.as_bytes()[offset..].as_ptr() == other.as_ptr())
+      .and_then(|offset| self.get(..offset))
+    {
+      Ok(ret)
+    } else {
+      Err(self)
+    }
+  }
+
+  fn cursor(&self) -> Option<usize> {
+    Some(self.as_ptr() as usize)
+  }
+}
+
+/// Offset is the address of the cursor
+impl<'a> StreamPosition for &'a str {
+  fn offset(&self) -> usize {
+    self.as_ptr() as usize
+  }
+}
+
+impl<'a, T> Streaming for &'a [T]
+where
+  T: Clone + Debug + Eq,
+{
   type Error = Infallible;
-  type Item = u8;
-  type Span = &'a [u8];
+  type Item = T;
+  type Span = &'a [T];
 
   fn split_first(self) -> Split<Self::Item, Self, Self::Error> {
     if let [first, stream @ ..] = self {
       Split::Success {
-        item: *first,
+        item: first.clone(),
         stream,
       }
     } else {
@@ -185,7 +245,7 @@ impl<'a> Streaming for &'a [u8] {
 
   fn split_at(self, mid: usize) -> Split<Self, Self, Self::Error> {
     if mid <= self.len() {
-      let (head, tail) = <[u8]>::split_at(self, mid);
+      let (head, tail) = <[T]>::split_at(self, mid);
       Split::Success {
         item: head,
         stream: tail,
@@ -198,7 +258,7 @@ impl<'a> Streaming for &'a [u8] {
   fn split_last(self) -> Split<Self::Item, Self, Self::Error> {
     if let [head @ .., last] = self {
       Split::Success {
-        item: *last,
+        item: last.clone(),
         stream: head,
       }
     } else {
@@ -225,35 +285,89 @@ impl<'a> Streaming for &'a [u8] {
       Err(self)
     }
   }
+
+  fn cursor(&self) -> Option<usize> {
+    Some(self.as_ptr() as usize)
+  }
+}
+
+/// Offset is the address of the cursor
+impl<'a, T> StreamPosition for &'a [T]
+where
+  T: Clone + Debug + Eq,
+{
+  fn offset(&self) -> usize {
+    self.as_ptr() as usize
+  }
+}
+
+/// Iterator over the items of a stream, see [Streaming::iter]
+#[derive(Clone, Debug)]
+pub struct StreamIter<Stream: Streaming> {
+  stream: Stream,
+  error: Option<Stream::Error>,
+}
+
+impl<Stream: Streaming> StreamIter<Stream> {
+  /// Return the stream of the remaining items
+  pub const fn stream(&self) -> &Stream {
+    &self.stream
+  }
+
+  /// Return the error of the stream if it's why iteration ended
+  pub const fn error(&self) -> Option<&Stream::Error> {
+    self.error.as_ref()
+  }
+
+  /// Return the stream of the remaining items
+  pub fn into_inner(self) -> Stream {
+    self.stream
+  }
 }
 
-// struct StreamIter<Stream> {
-//   stream: Stream,
-// }
-
-// impl<Stream> Iterator for StreamIter<Stream>
-// where
-//   Stream: Streaming + Clone,
-// {
-//   type Item = Stream::Item;
-
-//   fn next(&mut self) -> std::option::Option<<Self as Iterator>::Item> {
-//     match self.stream.clone().split_first() {
-//       SplitResult::Ok { token, stream } => {
-//         self.stream = stream;
-//         Some(token)
-//       }
-//       _ => None,
-//     }
-//   }
-// }
+impl<Stream: Streaming> Iterator for StreamIter<Stream> {
+  type Item = Stream::Item;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.error.is_some() {
+      return None;
+    }
+    match self.stream.clone().split_first() {
+      Split::Success { item, stream } => {
+        self.stream = stream;
+        Some(item)
+      }
+      Split::NotEnoughItem(_) => None,
+      Split::Error(error) => {
+        self.error = Some(error);
+        None
+      }
+    }
+  }
+}
 
 #[cfg(test)]
 mod tests {
   use super::{
     Split,
+    StreamPosition,
     Streaming,
   };
+  use crate::{
+    base::{
+      is,
+      list,
+      none_of,
+      one_of,
+      tag,
+      take,
+    },
+    context::Ignore,
+    utils::Utils,
+    Parse,
+    Parsed,
+    Success,
+  };
 
   #[test]
   fn split_first_slice() {
@@ -269,23 +383,23 @@ mod tests {
     assert_eq!(Streaming::split_first(stream), expected);
   }
 
-  // #[test]
-  // fn split_first_str() {
-  //   let stream = &"abcd"[..];
-  //   let expected = SplitResult::Ok {
-  //     token: 'a',
-  //     stream: &stream[1..],
-  //   };
-  //   assert_eq!(Streaming::split_first(stream), expected);
+  #[test]
+  fn split_first_str() {
+    let stream = "❤bcd";
+    let expected = Split::Success {
+      item: '❤',
+      stream: &stream['❤'.len_utf8()..],
+    };
+    assert_eq!(Streaming::split_first(stream), expected);
 
-  //   let stream = &b""[..];
-  //   let expected = SplitResult::NotEnoughItem(stream);
-  //   assert_eq!(Streaming::split_first(stream), expected);
-  // }
+    let stream = "";
+    let expected = Split::NotEnoughItem(stream);
+    assert_eq!(Streaming::split_first(stream), expected);
+  }
 
   #[test]
   fn split_at_slice() {
-    let stream = &[0, 1, 2, 3, 4][..];
+    let stream = &[0_u8, 1, 2, 3, 4][..];
     for n in 0..stream.len() {
       let expected = Split::Success {
         item: &stream[..n],
@@ -299,22 +413,22 @@ mod tests {
     assert_eq!(Streaming::split_at(stream, n), expected);
   }
 
-  // #[test]
-  // fn split_at_str() {
-  //   let stream = &"abcd"[..];
-
-  //   for (n, _) in stream.chars().enumerate() {
-  //     let expected = SplitResult::Ok {
-  //       token: &stream[..n],
-  //       stream: &stream[n..],
-  //     };
-  //     assert_eq!(Streaming::split_at(stream, n), expected);
-  //   }
+  #[test]
+  fn split_at_str() {
+    let stream = "a❤cé";
+    let boundaries = [0, 1, 4, 5, 7];
+    for (n, &i) in boundaries.iter().enumerate() {
+      let expected = Split::Success {
+        item: &stream[..i],
+        stream: &stream[i..],
+      };
+      assert_eq!(Streaming::split_at(stream, n), expected);
+    }
 
-  //   let n = stream.len() + 1;
-  //   let expected = SplitResult::NotEnoughItem(stream);
-  //   assert_eq!(Streaming::split_at(stream, n), expected);
-  // }
+    let n = boundaries.len();
+    let expected = Split::NotEnoughItem(stream);
+    assert_eq!(Streaming::split_at(stream, n), expected);
+  }
 
   #[test]
   fn split_last_slice() {
@@ -330,29 +444,29 @@ mod tests {
     assert_eq!(Streaming::split_last(stream), expected);
   }
 
-  // #[test]
-  // fn split_last_str() {
-  //   let stream = &"abcd"[..];
-  //   let expected = SplitResult::Ok {
-  //     token: 'd',
-  //     stream: &stream[..3],
-  //   };
-  //   assert_eq!(Streaming::split_last(stream), expected);
+  #[test]
+  fn split_last_str() {
+    let stream = "abc❤";
+    let expected = Split::Success {
+      item: '❤',
+      stream: &stream[..3],
+    };
+    assert_eq!(Streaming::split_last(stream), expected);
 
-  //   let stream = &b""[..];
-  //   let expected = SplitResult::NotEnoughItem(stream);
-  //   assert_eq!(Streaming::split_last(stream), expected);
-  // }
+    let stream = "";
+    let expected = Split::NotEnoughItem(stream);
+    assert_eq!(Streaming::split_last(stream), expected);
+  }
 
   #[test]
   fn diff_slice_all() {
-    let stream = &[0, 1, 2, 3, 4, 5, 6][..];
+    let stream = &[0_u8, 1, 2, 3, 4, 5, 6][..];
     assert_eq!(stream.diff(&&stream[stream.len()..]), Ok(&stream[..]));
   }
 
   #[test]
   fn diff_slice_mid() {
-    let stream = &[0, 1, 2, 3, 4, 5, 6][..];
+    let stream = &[0_u8, 1, 2, 3, 4, 5, 6][..];
     assert_eq!(
       stream.diff(&&stream[stream.len() / 2..]),
       Ok(&stream[..stream.len() / 2])
@@ -361,34 +475,133 @@ mod tests {
 
   #[test]
   fn diff_slice_error() {
-    let stream = &[0, 1, 2, 3, 4, 5, 6][..];
+    let stream = &[0_u8, 1, 2, 3, 4, 5, 6][..];
     assert_eq!(
       stream[..stream.len() / 2].as_ref().diff(&&stream[..]),
       Err(&stream[..stream.len() / 2])
     );
   }
 
-  // #[test]
-  // fn offset_str_same() {
-  //   let stream = &"abcdefg"[..];
-  //   assert_eq!(stream.diff(&&stream[stream.len()..]), Ok(&stream[..]));
-  // }
-
-  // #[test]
-  // fn offset_str_after() {
-  //   let stream = &"abcdefg"[..];
-  //   assert_eq!(
-  //     stream.diff(&&stream[stream.len() / 2..]),
-  //     Ok(&stream[..stream.len() / 2])
-  //   );
-  // }
-
-  // #[test]
-  // fn offset_str_before() {
-  //   let stream = &"abcdefg"[..];
-  //   assert_eq!(
-  //     (&stream[..stream.len() / 2]).diff(&&stream[..]),
-  //     Err(&stream[..stream.len() / 2])
-  //   );
-  // }
+  #[test]
+  fn diff_str_all() {
+    let stream = "abcdefg";
+    assert_eq!(stream.diff(&&stream[stream.len()..]), Ok(stream));
+  }
+
+  #[test]
+  fn diff_str_mid() {
+    let stream = "abc❤efg";
+    assert_eq!(stream.diff(&&stream[6..]), Ok(&stream[..6]));
+  }
+
+  #[test]
+  fn diff_str_error() {
+    let stream = "abcdefg";
+    assert_eq!(
+      stream[..stream.len() / 2].diff(&stream),
+      Err(&stream[..stream.len() / 2])
+    );
+    let copy = *b"abcdefg";
+    let other = core::str::from_utf8(&copy).unwrap();
+    assert_eq!(stream.diff(&other), Err(stream));
+  }
+
+  #[test]
+  fn offset_slice() {
+    let stream = &b"abc"[..];
+    let Split::Success { stream: next, .. } = Streaming::split_first(stream) else {
+      panic!()
+    };
+    assert_eq!(next.offset(), stream.offset() + 1);
+    assert_eq!(stream.offset(), (&stream[..1]).offset());
+  }
+
+  #[test]
+  fn iter_str() {
+    let mut iter = "❤bcd".iter();
+    assert_eq!(iter.next(), Some('❤'));
+    assert_eq!(iter.stream(), &"bcd");
+    assert!(iter.by_ref().eq("bcd".chars()));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.error(), None);
+    assert_eq!(iter.into_inner(), "");
+  }
+
+  #[test]
+  fn str_base() {
+    let stream = "fn ❤(x)";
+    let Success { token, stream } = tag::<_, Ignore>("fn")
+      .and_drop(is(' '))
+      .parse(stream)
+      .unwrap();
+    assert_eq!(token, "fn");
+    let Success { token, stream } = one_of::<_, Ignore, _>(&['❤', '♥'])
+      .and(is('('))
+      .span()
+      .parse(stream)
+      .unwrap();
+    assert_eq!(token.token, ('❤', '('));
+    assert_eq!(token.stream, "❤(");
+    assert_eq!(stream, "x)");
+  }
+
+  #[test]
+  fn str_tag_no_ascii() {
+    let stream = "❤é";
+    assert_eq!(
+      tag::<_, Ignore>("❤").parse(stream),
+      Parsed::Success {
+        token: "❤",
+        stream: "é",
+      }
+    );
+    assert_eq!(
+      tag::<_, Ignore>("❤é").parse(stream),
+      Parsed::Success {
+        token: "❤é",
+        stream: "",
+      }
+    );
+    assert_eq!(
+      tag::<_, Ignore>("❤e").parse(stream),
+      Parsed::Failure(Ignore)
+    );
+  }
+
+  #[derive(Clone, Debug, PartialEq, Eq)]
+  enum Token {
+    Let,
+    Ident(&'static str),
+    Equal,
+    Number(u64),
+    Semicolon,
+  }
+
+  #[test]
+  fn token_slice_base() {
+    let stream = &[
+      Token::Let,
+      Token::Ident("x"),
+      Token::Equal,
+      Token::Number(42),
+      Token::Semicolon,
+      Token::Semicolon,
+    ][..];
+    let Success { token, stream } = is::<_, Ignore, _>(Token::Let)
+      .drop_and(none_of(&[Token::Let, Token::Equal]))
+      .and_drop(list(&[Token::Equal]))
+      .parse(stream)
+      .unwrap();
+    assert_eq!(token, Token::Ident("x"));
+    let Success { token, stream } = take::<_, Ignore>(1)
+      .and_drop(one_of(&[Token::Semicolon]))
+      .parse(stream)
+      .unwrap();
+    assert_eq!(token, &[Token::Number(42)][..]);
+    assert_eq!(stream, &[Token::Semicolon][..]);
+    assert_eq!(
+      is::<_, Ignore, _>(Token::Let).parse(stream),
+      Parsed::Failure(Ignore)
+    );
+  }
 }
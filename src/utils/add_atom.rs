@@ -2,6 +2,7 @@ use crate::{
   Contexting,
   Parse,
   Parsed,
+  Streaming,
 };
 
 /// Implementation of [crate::utils::Utils::add_atom]
@@ -13,6 +14,7 @@ pub struct AddAtom<Parser, F> {
 
 impl<Stream, Context, Parser, F, Atom> Parse<Stream, Context> for AddAtom<Parser, F>
 where
+  Stream: Streaming,
   Parser: Parse<Stream, Context>,
   F: Fn() -> Atom,
   Context: Contexting<Atom>,
@@ -20,16 +22,18 @@ where
   type Token = Parser::Token;
 
   fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    let cursor = stream.cursor();
     self
       .parser
       .parse(stream)
-      .map_context(|context| context.add((self.f)()))
+      .map_context(|context| context.add_at((self.f)(), cursor))
   }
 }
 
 /// Function style version of [crate::utils::Utils::add_atom]
 pub fn add_atom<Stream, Context, Parser, F, Atom>(parser: Parser, f: F) -> AddAtom<Parser, F>
 where
+  Stream: Streaming,
   Parser: Parse<Stream, Context>,
   F: Fn() -> Atom,
   Context: Contexting<Atom>,
@@ -0,0 +1,254 @@
+use crate::{
+  utils::UtilsAtom,
+  Contexting,
+  CoreAtom,
+  Parse,
+  Parsed,
+  Split,
+  Streaming,
+  Success,
+};
+
+/// Policy about items the inner parser of [bounded] or [length_value]
+/// didn't consume
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Leftover {
+  /// Return a Failure with [UtilsAtom::Leftover]
+  Deny,
+  /// Items left are dropped
+  Allow,
+}
+
+/// Implementation of [bounded]
+#[derive(Clone)]
+pub struct Bounded<Parser> {
+  parser: Parser,
+  n: usize,
+  leftover: Leftover,
+}
+
+impl<Stream, Context, Parser> Parse<Stream, Context> for Bounded<Parser>
+where
+  Stream: Streaming,
+  Parser: Parse<Stream::Span, Context>,
+  Context: Contexting<CoreAtom<Stream>>,
+  Context: Contexting<CoreAtom<Stream::Span>>,
+  Context: Contexting<UtilsAtom<Stream>>,
+{
+  type Token = Parser::Token;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    parse_bounded(&mut self.parser, self.n, self.leftover, stream)
+  }
+}
+
+/// Give to parser a Span of exactly n items taken from the stream.
+/// leftover decide what happen if parser doesn't consume all the Span.
+pub const fn bounded<Stream, Context, Parser>(
+  parser: Parser, n: usize, leftover: Leftover,
+) -> Bounded<Parser>
+where
+  Stream: Streaming,
+  Parser: Parse<Stream::Span, Context>,
+  Context: Contexting<CoreAtom<Stream>>,
+  Context: Contexting<CoreAtom<Stream::Span>>,
+  Context: Contexting<UtilsAtom<Stream>>,
+{
+  Bounded {
+    parser,
+    n,
+    leftover,
+  }
+}
+
+/// Implementation of [length_value]
+#[derive(Clone)]
+pub struct LengthValue<LenParser, Parser> {
+  len_parser: LenParser,
+  parser: Parser,
+  leftover: Leftover,
+}
+
+impl<LenParser, Parser> LengthValue<LenParser, Parser> {
+  /// Change the policy about items not consumed by parser, default is
+  /// [Leftover::Deny]
+  pub fn leftover(self, leftover: Leftover) -> Self {
+    Self { leftover, ..self }
+  }
+}
+
+impl<Stream, Context, LenParser, Parser> Parse<Stream, Context> for LengthValue<LenParser, Parser>
+where
+  Stream: Streaming,
+  LenParser: Parse<Stream, Context>,
+  LenParser::Token: TryInto<usize>,
+  Parser: Parse<Stream::Span, Context>,
+  Context: Contexting<CoreAtom<Stream>>,
+  Context: Contexting<CoreAtom<Stream::Span>>,
+  Context: Contexting<UtilsAtom<Stream>>,
+{
+  type Token = Parser::Token;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    let Success { token: len, stream } = self.len_parser.parse(stream)?;
+    match len.try_into() {
+      Ok(n) => parse_bounded(&mut self.parser, n, self.leftover, stream),
+      Err(_) => Parsed::Failure(Context::new(UtilsAtom::Length)),
+    }
+  }
+}
+
+/// Read a length with len_parser then give to parser a Span of this length
+/// like [bounded]. By default parser must consume all the Span, see
+/// [LengthValue::leftover].
+pub const fn length_value<Stream, Context, LenParser, Parser>(
+  len_parser: LenParser, parser: Parser,
+) -> LengthValue<LenParser, Parser>
+where
+  Stream: Streaming,
+  LenParser: Parse<Stream, Context>,
+  LenParser::Token: TryInto<usize>,
+  Parser: Parse<Stream::Span, Context>,
+  Context: Contexting<CoreAtom<Stream>>,
+  Context: Contexting<CoreAtom<Stream::Span>>,
+  Context: Contexting<UtilsAtom<Stream>>,
+{
+  LengthValue {
+    len_parser,
+    parser,
+    leftover: Leftover::Deny,
+  }
+}
+
+fn parse_bounded<Stream, Context, Parser>(
+  parser: &mut Parser, n: usize, leftover: Leftover, stream: Stream,
+) -> Parsed<Parser::Token, Stream, Context>
+where
+  Stream: Streaming,
+  Parser: Parse<Stream::Span, Context>,
+  Context: Contexting<CoreAtom<Stream>>,
+  Context: Contexting<CoreAtom<Stream::Span>>,
+  Context: Contexting<UtilsAtom<Stream>>,
+{
+  let (span, stream) = match stream.split_at(n) {
+    Split::Success { item, stream } => (item, stream),
+    Split::NotEnoughItem(stream) => {
+      return Parsed::Failure(Context::new(CoreAtom::EndOfStream { stream }));
+    }
+    Split::Error(error) => return Parsed::Error(Context::new(CoreAtom::Error { error })),
+  };
+
+  let Success {
+    token,
+    stream: span,
+  } = parser.parse(span)?;
+  if leftover == Leftover::Deny {
+    match span.split_first() {
+      Split::Success { .. } => return Parsed::Failure(Context::new(UtilsAtom::Leftover)),
+      Split::NotEnoughItem(_) => {}
+      Split::Error(error) => {
+        return Parsed::Error(Context::new(CoreAtom::<Stream::Span>::Error { error }));
+      }
+    }
+  }
+
+  Parsed::Success { token, stream }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use super::{
+    bounded,
+    length_value,
+    Leftover,
+  };
+  use crate::{
+    base::{
+      is,
+      octet,
+      primitive::u16_be,
+      BaseAtom,
+    },
+    context::{
+      First,
+      Keep,
+    },
+    utils::{
+      Acc,
+      Utils,
+      UtilsAtom,
+    },
+    CoreAtom,
+    Parse,
+    Parsed,
+    ProvideElement,
+    Success,
+  };
+
+  type Stream = &'static [u8];
+
+  #[derive(Display, Debug, From, PartialEq)]
+  enum FromAtom {
+    Utils(UtilsAtom<Stream>),
+    Base(BaseAtom<u8>),
+    Core(CoreAtom<Stream>),
+  }
+
+  type HandleAtom = Keep<First, FromAtom>;
+
+  fn assert_failure<Token>(parsed: Parsed<Token, Stream, HandleAtom>, atom: FromAtom) {
+    match parsed {
+      Parsed::Failure(context) => assert_eq!(context.last(), &atom),
+      _ => panic!(),
+    }
+  }
+
+  #[test]
+  fn bounded_leftover() {
+    let stream = &b"abc"[..];
+    let parsed = bounded(octet, 2, Leftover::Deny).parse(stream);
+    assert_failure(parsed, FromAtom::Utils(UtilsAtom::Leftover));
+
+    let parsed: Parsed<_, _, HandleAtom> = bounded(octet, 2, Leftover::Allow).parse(stream);
+    let Success { token, stream } = parsed.unwrap();
+    assert_eq!(token, b'a');
+    assert_eq!(stream, b"c");
+
+    let parsed = bounded(octet, 4, Leftover::Allow).parse(stream);
+    assert_failure(parsed, FromAtom::Core(CoreAtom::EndOfStream { stream }));
+  }
+
+  #[test]
+  fn tlv() {
+    let stream = &b"\x00\x03abc\x00\x01d!"[..];
+    let mut value = length_value(u16_be, octet.fold_bounds(.., Vec::new, Acc::acc));
+    let parsed: Parsed<_, _, HandleAtom> = value.parse(stream);
+    let Success { token, stream } = parsed.unwrap();
+    assert_eq!(token, b"abc");
+    let parsed: Parsed<_, _, HandleAtom> = value.parse(stream);
+    let Success { token, stream } = parsed.unwrap();
+    assert_eq!(token, b"d");
+    assert_eq!(stream, b"!");
+  }
+
+  #[test]
+  fn length_value_leftover() {
+    let stream = &b"\x00\x02ab!"[..];
+    let parsed = length_value(u16_be, is(b'a')).parse(stream);
+    assert_failure(parsed, FromAtom::Utils(UtilsAtom::Leftover));
+
+    let parsed: Parsed<_, _, HandleAtom> = length_value(u16_be, is(b'a'))
+      .leftover(Leftover::Allow)
+      .parse(stream);
+    let Success { token, stream } = parsed.unwrap();
+    assert_eq!(token, b'a');
+    assert_eq!(stream, b"!");
+  }
+}
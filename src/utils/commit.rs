@@ -0,0 +1,47 @@
+use crate::{
+  Parse,
+  Parsed,
+  Success,
+};
+
+/// Implementation of [crate::utils::Utils::commit]
+#[derive(Clone)]
+pub struct Commit<ParserA, ParserB> {
+  parser_a: ParserA,
+  parser_b: ParserB,
+}
+
+impl<TokenA, Stream, Context, ParserA, TokenB, ParserB> Parse<Stream, Context>
+  for Commit<ParserA, ParserB>
+where
+  ParserA: Parse<Stream, Context, Token = TokenA>,
+  ParserB: Parse<Stream, Context, Token = TokenB>,
+{
+  type Token = (TokenA, TokenB);
+
+  fn parse(&mut self, stream: Stream) -> Parsed<(TokenA, TokenB), Stream, Context> {
+    let Success { token, stream } = self.parser_a.parse(stream)?;
+
+    match self.parser_b.parse(stream) {
+      Parsed::Success {
+        token: token_b,
+        stream,
+      } => Parsed::Success {
+        token: (token, token_b),
+        stream,
+      },
+      Parsed::Failure(context) | Parsed::Error(context) => Parsed::Error(context),
+    }
+  }
+}
+
+/// Function style version of [crate::utils::Utils::commit]
+pub const fn commit<TokenA, Stream, Context, ParserA, TokenB, ParserB>(
+  parser_a: ParserA, parser_b: ParserB,
+) -> Commit<ParserA, ParserB>
+where
+  ParserA: Parse<Stream, Context, Token = TokenA>,
+  ParserB: Parse<Stream, Context, Token = TokenB>,
+{
+  Commit { parser_a, parser_b }
+}
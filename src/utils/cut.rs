@@ -0,0 +1,182 @@
+use crate::{
+  Parse,
+  Parsed,
+};
+
+/// Implementation of [crate::utils::Utils::cut]
+#[derive(Clone)]
+pub struct Cut<Parser> {
+  parser: Parser,
+}
+
+impl<Stream, Context, Parser> Parse<Stream, Context> for Cut<Parser>
+where
+  Parser: Parse<Stream, Context>,
+{
+  type Token = Parser::Token;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    match self.parser.parse(stream) {
+      Parsed::Failure(context) => Parsed::Error(context),
+      parsed => parsed,
+    }
+  }
+}
+
+/// Function style version of [crate::utils::Utils::cut]
+pub const fn cut<Stream, Context, Parser>(parser: Parser) -> Cut<Parser>
+where
+  Parser: Parse<Stream, Context>,
+{
+  Cut { parser }
+}
+
+#[cfg(test)]
+mod tests {
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use crate::{
+    base::{
+      is,
+      BaseAtom,
+    },
+    context::{
+      First,
+      Keep,
+    },
+    utils::{
+      Utils,
+      UtilsAtom,
+    },
+    CoreAtom,
+    Parse,
+    Parsed,
+    ProvideElement,
+  };
+
+  type Stream = &'static [u8];
+
+  #[derive(Display, Debug, From, PartialEq)]
+  enum FromAtom {
+    Base(BaseAtom<u8>),
+    Core(CoreAtom<Stream>),
+    Utils(UtilsAtom<Stream>),
+  }
+
+  type HandleAtom = Keep<First, FromAtom>;
+
+  fn assert_error<Token>(parsed: Parsed<Token, Stream, HandleAtom>, atom: FromAtom) {
+    match parsed {
+      Parsed::Error(context) => assert_eq!(context.last(), &atom),
+      _ => panic!(),
+    }
+  }
+
+  #[test]
+  fn cut() {
+    let stream = &b"ab"[..];
+    let parsed: Parsed<_, _, HandleAtom> = is(b'x').parse(stream);
+    assert!(matches!(parsed, Parsed::Failure(_)));
+
+    assert_error(
+      is(b'x').cut().parse(stream),
+      FromAtom::Base(BaseAtom::Is {
+        t: Some(b'a'),
+        expect: b'x',
+      }),
+    );
+
+    // or would succeed with the second branch
+    let parsed: Parsed<_, _, HandleAtom> = is(b'x').or(is(b'a')).parse(stream);
+    assert!(parsed.is_success());
+    let parsed = is(b'x').cut().or(is(b'a')).parse(stream);
+    assert_error(
+      parsed,
+      FromAtom::Base(BaseAtom::Is {
+        t: Some(b'a'),
+        expect: b'x',
+      }),
+    );
+  }
+
+  #[test]
+  fn or() {
+    let stream = &b"ac"[..];
+    let parsed: Parsed<_, _, HandleAtom> = is(b'a')
+      .and(is(b'b'))
+      .or(is(b'a').and(is(b'c')))
+      .parse(stream);
+    assert!(parsed.is_success());
+
+    let parsed = is(b'a')
+      .commit(is(b'b'))
+      .or(is(b'a').and(is(b'c')))
+      .parse(stream);
+    assert_error(
+      parsed,
+      FromAtom::Base(BaseAtom::Is {
+        t: Some(b'c'),
+        expect: b'b',
+      }),
+    );
+  }
+
+  #[test]
+  fn or_error() {
+    let stream = &b"ac"[..];
+    let parsed = is(b'x')
+      .map(|x| (x, x))
+      .or(is(b'a').commit(is(b'b')))
+      .parse(stream);
+    assert_error(
+      parsed,
+      FromAtom::Base(BaseAtom::Is {
+        t: Some(b'c'),
+        expect: b'b',
+      }),
+    );
+  }
+
+  #[test]
+  fn array() {
+    let stream = &b"bc"[..];
+    let parsed = [
+      is(b'a').commit(is(b'b')),
+      is(b'b').commit(is(b'b')),
+      is(b'b').commit(is(b'c')),
+    ]
+    .parse(stream);
+    assert_error(
+      parsed,
+      FromAtom::Base(BaseAtom::Is {
+        t: Some(b'c'),
+        expect: b'b',
+      }),
+    );
+  }
+
+  #[test]
+  fn fold_bounds() {
+    let stream = &b"ababa!"[..];
+    let parsed: Parsed<_, _, HandleAtom> = is(b'a')
+      .and(is(b'b'))
+      .fold_bounds(.., || (), |_, _| ())
+      .parse(stream);
+    assert_eq!(parsed.unwrap().stream, b"a!");
+
+    let parsed = is(b'a')
+      .commit(is(b'b'))
+      .fold_bounds(.., || (), |_, _| ())
+      .parse(stream);
+    assert_error(
+      parsed,
+      FromAtom::Base(BaseAtom::Is {
+        t: Some(b'!'),
+        expect: b'b',
+      }),
+    );
+  }
+}
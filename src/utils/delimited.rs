@@ -0,0 +1,232 @@
+use core::fmt::{
+  self,
+  Display,
+  Formatter,
+};
+
+use crate::{
+  utils::UtilsAtom,
+  Contexting,
+  Parse,
+  Parsed,
+  Streaming,
+  Success,
+};
+
+/// Atom of delimited combinator, added when the closing delimiter is missing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnclosedAtom<Stream: Streaming> {
+  /// Span of the opening delimiter
+  pub open: Stream::Span,
+}
+
+impl<Stream: Streaming> Display for UnclosedAtom<Stream> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    write!(f, "unclosed {:?} opened here", self.open)
+  }
+}
+
+/// Implementation of [crate::utils::Utils::delimited]
+#[derive(Clone)]
+pub struct Delimited<Open, Parser, Close> {
+  open: Open,
+  parser: Parser,
+  close: Close,
+}
+
+/// Implementation of [crate::utils::Utils::surrounded_by]
+#[derive(Clone)]
+pub struct SurroundedBy<Parser, Quote> {
+  parser: Parser,
+  quote: Quote,
+}
+
+// return the span of open delimiter
+fn open<Stream, Context, Open>(
+  open: &mut Open, stream: Stream,
+) -> Parsed<Stream::Span, Stream, Context>
+where
+  Stream: Streaming,
+  Open: Parse<Stream, Context>,
+  Context: Contexting<UtilsAtom<Stream>>,
+{
+  let Success {
+    token: _,
+    stream: stream_success,
+  } = open.parse(stream.clone())?;
+  match stream.diff(&stream_success) {
+    Ok(span) => Parsed::Success {
+      token: span,
+      stream: stream_success,
+    },
+    Err(stream) => Parsed::Error(Context::new(UtilsAtom::Diff {
+      stream,
+      stream_success,
+    })),
+  }
+}
+
+fn close<Stream, Context, Close, Token>(
+  close: &mut Close, open: Stream::Span, token: Token, stream: Stream,
+) -> Parsed<Token, Stream, Context>
+where
+  Stream: Streaming,
+  Close: Parse<Stream, Context>,
+  Context: Contexting<UnclosedAtom<Stream>>,
+{
+  match close.parse(stream) {
+    Parsed::Success { token: _, stream } => Parsed::Success { token, stream },
+    Parsed::Failure(context) => Parsed::Failure(context.add(UnclosedAtom { open })),
+    Parsed::Error(context) => Parsed::Error(context.add(UnclosedAtom { open })),
+  }
+}
+
+impl<Stream, Context, Open, Parser, Close> Parse<Stream, Context> for Delimited<Open, Parser, Close>
+where
+  Stream: Streaming,
+  Open: Parse<Stream, Context>,
+  Parser: Parse<Stream, Context>,
+  Close: Parse<Stream, Context>,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Context: Contexting<UnclosedAtom<Stream>>,
+{
+  type Token = Parser::Token;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    let Success {
+      token: span,
+      stream,
+    } = open(&mut self.open, stream)?;
+    let Success { token, stream } = self.parser.parse(stream)?;
+    close(&mut self.close, span, token, stream)
+  }
+}
+
+impl<Stream, Context, Parser, Quote> Parse<Stream, Context> for SurroundedBy<Parser, Quote>
+where
+  Stream: Streaming,
+  Parser: Parse<Stream, Context>,
+  Quote: Parse<Stream, Context>,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Context: Contexting<UnclosedAtom<Stream>>,
+{
+  type Token = Parser::Token;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    let Success {
+      token: span,
+      stream,
+    } = open(&mut self.quote, stream)?;
+    let Success { token, stream } = self.parser.parse(stream)?;
+    close(&mut self.quote, span, token, stream)
+  }
+}
+
+/// Function style version of [crate::utils::Utils::delimited]
+pub const fn delimited<Stream, Context, Open, Parser, Close>(
+  open: Open, parser: Parser, close: Close,
+) -> Delimited<Open, Parser, Close>
+where
+  Stream: Streaming,
+  Open: Parse<Stream, Context>,
+  Parser: Parse<Stream, Context>,
+  Close: Parse<Stream, Context>,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Context: Contexting<UnclosedAtom<Stream>>,
+{
+  Delimited {
+    open,
+    parser,
+    close,
+  }
+}
+
+/// Function style version of [crate::utils::Utils::surrounded_by]
+pub const fn surrounded_by<Stream, Context, Parser, Quote>(
+  parser: Parser, quote: Quote,
+) -> SurroundedBy<Parser, Quote>
+where
+  Stream: Streaming,
+  Parser: Parse<Stream, Context>,
+  Quote: Parse<Stream, Context>,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Context: Contexting<UnclosedAtom<Stream>>,
+{
+  SurroundedBy { parser, quote }
+}
+
+#[cfg(test)]
+mod tests {
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use super::{
+    delimited,
+    UnclosedAtom,
+  };
+  use crate::{
+    base::{
+      is,
+      tag,
+      BaseAtom,
+    },
+    context::{
+      Keep,
+      Last,
+    },
+    utils::{
+      Utils,
+      UtilsAtom,
+    },
+    CoreAtom,
+    Parse,
+    Parsed,
+    ProvideElement,
+    Success,
+  };
+
+  type Stream = &'static str;
+
+  #[derive(Display, Debug, From, PartialEq)]
+  enum FromAtom {
+    Utils(UtilsAtom<Stream>),
+    Base(BaseAtom<char>),
+    Tag(BaseAtom<u8>),
+    Core(CoreAtom<Stream>),
+    Unclosed(UnclosedAtom<Stream>),
+  }
+
+  type HandleAtom = Keep<Last, FromAtom>;
+
+  fn assert_unclosed<Token>(parsed: Parsed<Token, Stream, HandleAtom>, open: Stream) {
+    match parsed {
+      Parsed::Failure(context) => {
+        assert_eq!(context.last(), &FromAtom::Unclosed(UnclosedAtom { open }))
+      }
+      _ => panic!(),
+    }
+  }
+
+  #[test]
+  fn delimited_unclosed() {
+    let mut parser = delimited(tag("(*"), is('a'), tag("*)"));
+    let parsed: Parsed<_, _, HandleAtom> = parser.parse("(*a*)!");
+    let Success { token, stream } = parsed.unwrap();
+    assert_eq!(token, 'a');
+    assert_eq!(stream, "!");
+
+    assert_unclosed(parser.parse("(*a!"), "(*");
+    assert!(matches!(parser.parse("(*b*)"), Parsed::Failure(_)));
+  }
+
+  #[test]
+  fn surrounded_by() {
+    let mut parser = is('a').surrounded_by(is('"'));
+    let parsed: Parsed<_, _, HandleAtom> = parser.parse("\"a\"");
+    assert_eq!(parsed.unwrap().token, 'a');
+
+    assert_unclosed(parser.parse("\"a"), "\"");
+  }
+}
@@ -0,0 +1,439 @@
+use core::{
+  convert::Infallible,
+  fmt::{
+    self,
+    Display,
+    Formatter,
+  },
+};
+
+use crate::{
+  utils::UtilsAtom,
+  Contexting,
+  Parse,
+  Parsed,
+  Streaming,
+  Success,
+};
+
+/// Associativity of an infix operator of [crate::utils::Utils::expression]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+  /// `a - b - c` is `(a - b) - c`
+  Left,
+  /// `a ^ b ^ c` is `a ^ (b ^ c)`
+  Right,
+  /// `a == b == c` is not allowed
+  None,
+}
+
+/// Atom of expression combinator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExpressionAtom<Stream: Streaming> {
+  /// When an operator is not followed by an operand
+  Operand {
+    /// Span of the operator
+    operator: Stream::Span,
+  },
+  /// When a non associative operator is chained with an operator of the same
+  /// binding power
+  NonAssociative {
+    /// Span of the second operator
+    operator: Stream::Span,
+  },
+}
+
+impl<Stream: Streaming> Display for ExpressionAtom<Stream> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    match self {
+      ExpressionAtom::Operand { operator } => write!(f, "expected operand after {:?}", operator),
+      ExpressionAtom::NonAssociative { operator } => {
+        write!(f, "operator {:?} is not associative", operator)
+      }
+    }
+  }
+}
+
+/// Type of prefix or postfix table of [Expression] when no table was given
+pub type NoOperator<Stream, Context> = fn(Stream) -> Parsed<(Infallible, u8), Stream, Context>;
+
+/// Type returned by [crate::utils::Utils::expression], an [Expression] without
+/// prefix and postfix operator
+pub type InfixExpression<Stream, Context, Atom, Infix, FInfix> = Expression<
+  Atom,
+  Infix,
+  FInfix,
+  NoOperator<Stream, Context>,
+  fn(
+    Infallible,
+    <Atom as Parse<Stream, Context>>::Token,
+  ) -> <Atom as Parse<Stream, Context>>::Token,
+  NoOperator<Stream, Context>,
+  fn(
+    <Atom as Parse<Stream, Context>>::Token,
+    Infallible,
+  ) -> <Atom as Parse<Stream, Context>>::Token,
+>;
+
+const fn no_prefix<Token>(op: Infallible, _token: Token) -> Token {
+  match op {}
+}
+
+const fn no_postfix<Token>(_token: Token, op: Infallible) -> Token {
+  match op {}
+}
+
+/// Implementation of [crate::utils::Utils::expression]
+#[derive(Clone)]
+pub struct Expression<Atom, Infix, FInfix, Prefix, FPrefix, Postfix, FPostfix> {
+  atom: Atom,
+  infix: Infix,
+  fold_infix: FInfix,
+  prefix: Option<Prefix>,
+  fold_prefix: FPrefix,
+  postfix: Option<Postfix>,
+  fold_postfix: FPostfix,
+}
+
+impl<Atom, Infix, FInfix, Prefix, FPrefix, Postfix, FPostfix>
+  Expression<Atom, Infix, FInfix, Prefix, FPrefix, Postfix, FPostfix>
+{
+  /// Set the table of prefix operator, prefix must produce the operator and
+  /// its binding power, fold is called with the operator and the operand.
+  pub fn prefix<NewPrefix, NewFPrefix>(
+    self, prefix: NewPrefix, fold: NewFPrefix,
+  ) -> Expression<Atom, Infix, FInfix, NewPrefix, NewFPrefix, Postfix, FPostfix> {
+    Expression {
+      atom: self.atom,
+      infix: self.infix,
+      fold_infix: self.fold_infix,
+      prefix: Some(prefix),
+      fold_prefix: fold,
+      postfix: self.postfix,
+      fold_postfix: self.fold_postfix,
+    }
+  }
+
+  /// Set the table of postfix operator, postfix must produce the operator and
+  /// its binding power, fold is called with the operand and the operator.
+  pub fn postfix<NewPostfix, NewFPostfix>(
+    self, postfix: NewPostfix, fold: NewFPostfix,
+  ) -> Expression<Atom, Infix, FInfix, Prefix, FPrefix, NewPostfix, NewFPostfix> {
+    Expression {
+      atom: self.atom,
+      infix: self.infix,
+      fold_infix: self.fold_infix,
+      prefix: self.prefix,
+      fold_prefix: self.fold_prefix,
+      postfix: Some(postfix),
+      fold_postfix: fold,
+    }
+  }
+}
+
+// return the span of the operator
+fn span<Stream, Context>(
+  stream: Stream, stream_success: Stream,
+) -> Parsed<Stream::Span, Stream, Context>
+where
+  Stream: Streaming,
+  Context: Contexting<UtilsAtom<Stream>>,
+{
+  match stream.diff(&stream_success) {
+    Ok(span) => Parsed::Success {
+      token: span,
+      stream: stream_success,
+    },
+    Err(stream) => Parsed::Error(Context::new(UtilsAtom::Diff {
+      stream,
+      stream_success,
+    })),
+  }
+}
+
+// binding power are doubled to handle associativity, the left one is compared
+// to the minimum of the current operand, the right one is the minimum of the
+// next operand
+fn binding(power: u8, associativity: Associativity) -> (u16, u16) {
+  let power = u16::from(power) * 2;
+  match associativity {
+    Associativity::Left | Associativity::None => (power, power + 1),
+    Associativity::Right => (power + 1, power),
+  }
+}
+
+impl<Atom, Infix, FInfix, Prefix, FPrefix, Postfix, FPostfix>
+  Expression<Atom, Infix, FInfix, Prefix, FPrefix, Postfix, FPostfix>
+{
+  // operator is the operator before this operand if any
+  fn expression<Stream, Context, InfixOp, PrefixOp, PostfixOp>(
+    &mut self, operator: Option<Stream::Span>, stream: Stream, min: u16,
+  ) -> Parsed<Atom::Token, Stream, Context>
+  where
+    Stream: Streaming,
+    Context: Contexting<UtilsAtom<Stream>>,
+    Context: Contexting<ExpressionAtom<Stream>>,
+    Atom: Parse<Stream, Context>,
+    Infix: Parse<Stream, Context, Token = (InfixOp, u8, Associativity)>,
+    FInfix: FnMut(Atom::Token, InfixOp, Atom::Token) -> Atom::Token,
+    Prefix: Parse<Stream, Context, Token = (PrefixOp, u8)>,
+    FPrefix: FnMut(PrefixOp, Atom::Token) -> Atom::Token,
+    Postfix: Parse<Stream, Context, Token = (PostfixOp, u8)>,
+    FPostfix: FnMut(Atom::Token, PostfixOp) -> Atom::Token,
+  {
+    let prefix = self
+      .prefix
+      .as_mut()
+      .map(|prefix| prefix.parse(stream.clone()));
+    let Success {
+      token: mut lhs,
+      mut stream,
+    } = match prefix {
+      Some(Parsed::Success {
+        token: (op, power),
+        stream: stream_op,
+      }) => {
+        let Success {
+          token: span,
+          stream,
+        } = span(stream, stream_op)?;
+        let Success { token, stream } =
+          self.expression(Some(span), stream, u16::from(power) * 2 + 1)?;
+        Success {
+          token: (self.fold_prefix)(op, token),
+          stream,
+        }
+      }
+      Some(Parsed::Error(context)) => return Parsed::Error(context),
+      Some(Parsed::Failure(_)) | None => match self.atom.parse(stream) {
+        Parsed::Failure(context) => {
+          return Parsed::Failure(match operator {
+            Some(operator) => context.add(ExpressionAtom::Operand { operator }),
+            None => context,
+          })
+        }
+        parsed => parsed?,
+      },
+    };
+
+    // power of the last non associative operator folded at this level
+    let mut non_associative = None;
+    loop {
+      if let Some(postfix) = &mut self.postfix {
+        match postfix.parse(stream.clone()) {
+          Parsed::Success {
+            token: (op, power),
+            stream: stream_op,
+          } if u16::from(power) * 2 >= min => {
+            lhs = (self.fold_postfix)(lhs, op);
+            stream = stream_op;
+            continue;
+          }
+          Parsed::Success { .. } | Parsed::Failure(_) => {}
+          Parsed::Error(context) => return Parsed::Error(context),
+        }
+      }
+
+      match self.infix.parse(stream.clone()) {
+        Parsed::Success {
+          token: (op, power, associativity),
+          stream: stream_op,
+        } => {
+          let (left, right) = binding(power, associativity);
+          if left < min {
+            break;
+          }
+          let Success {
+            token: span,
+            stream: stream_op,
+          } = span(stream, stream_op)?;
+          if associativity == Associativity::None && non_associative == Some(power) {
+            return Parsed::Failure(Context::new(ExpressionAtom::NonAssociative {
+              operator: span,
+            }));
+          }
+          let Success {
+            token: rhs,
+            stream: stream_rhs,
+          } = self.expression(Some(span), stream_op, right)?;
+          non_associative = (associativity == Associativity::None).then_some(power);
+          lhs = (self.fold_infix)(lhs, op, rhs);
+          stream = stream_rhs;
+        }
+        Parsed::Failure(_) => break,
+        Parsed::Error(context) => return Parsed::Error(context),
+      }
+    }
+
+    Parsed::Success { token: lhs, stream }
+  }
+}
+
+impl<
+    Stream,
+    Context,
+    Atom,
+    Infix,
+    InfixOp,
+    FInfix,
+    Prefix,
+    PrefixOp,
+    FPrefix,
+    Postfix,
+    PostfixOp,
+    FPostfix,
+  > Parse<Stream, Context> for Expression<Atom, Infix, FInfix, Prefix, FPrefix, Postfix, FPostfix>
+where
+  Stream: Streaming,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Context: Contexting<ExpressionAtom<Stream>>,
+  Atom: Parse<Stream, Context>,
+  Infix: Parse<Stream, Context, Token = (InfixOp, u8, Associativity)>,
+  FInfix: FnMut(Atom::Token, InfixOp, Atom::Token) -> Atom::Token,
+  Prefix: Parse<Stream, Context, Token = (PrefixOp, u8)>,
+  FPrefix: FnMut(PrefixOp, Atom::Token) -> Atom::Token,
+  Postfix: Parse<Stream, Context, Token = (PostfixOp, u8)>,
+  FPostfix: FnMut(Atom::Token, PostfixOp) -> Atom::Token,
+{
+  type Token = Atom::Token;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    self.expression(None, stream, 0)
+  }
+}
+
+/// Function style version of [crate::utils::Utils::expression]
+pub const fn expression<Stream, Context, Atom, Infix, InfixOp, FInfix>(
+  atom: Atom, infix: Infix, fold: FInfix,
+) -> InfixExpression<Stream, Context, Atom, Infix, FInfix>
+where
+  Stream: Streaming,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Context: Contexting<ExpressionAtom<Stream>>,
+  Atom: Parse<Stream, Context>,
+  Infix: Parse<Stream, Context, Token = (InfixOp, u8, Associativity)>,
+  FInfix: FnMut(Atom::Token, InfixOp, Atom::Token) -> Atom::Token,
+{
+  Expression {
+    atom,
+    infix,
+    fold_infix: fold,
+    prefix: None,
+    fold_prefix: no_prefix,
+    postfix: None,
+    fold_postfix: no_postfix,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::{
+    format,
+    string::{
+      String,
+      ToString,
+    },
+  };
+
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use super::{
+    Associativity,
+    ExpressionAtom,
+  };
+  use crate::{
+    base::{
+      is,
+      octet,
+      BaseAtom,
+    },
+    context::{
+      Keep,
+      Last,
+    },
+    utils::{
+      Utils,
+      UtilsAtom,
+    },
+    CoreAtom,
+    Parse,
+    Parsed,
+    ProvideElement,
+    Success,
+  };
+
+  type Stream = &'static [u8];
+
+  #[derive(Display, Debug, From, PartialEq)]
+  enum FromAtom {
+    Utils(UtilsAtom<Stream>),
+    Base(BaseAtom<u8>),
+    Core(CoreAtom<Stream>),
+    Expression(ExpressionAtom<Stream>),
+  }
+
+  type HandleAtom = Keep<Last, FromAtom>;
+
+  // print the expression with all parentheses
+  fn expression(stream: Stream) -> Parsed<String, Stream, HandleAtom> {
+    let digit = octet
+      .filter(u8::is_ascii_digit)
+      .map(|digit| char::from(digit).to_string());
+    let infix = [
+      is(b'=').to(('=', 0, Associativity::None)),
+      is(b'+').to(('+', 1, Associativity::Left)),
+      is(b'-').to(('-', 1, Associativity::Left)),
+      is(b'*').to(('*', 2, Associativity::Left)),
+      is(b'^').to(('^', 4, Associativity::Right)),
+    ];
+
+    digit
+      .expression(infix, |lhs, op, rhs| format!("({} {} {})", lhs, op, rhs))
+      .prefix(is(b'-').to(('-', 3)), |op: char, rhs: String| {
+        format!("({}{})", op, rhs)
+      })
+      .postfix(is(b'!').to(('!', 5)), |lhs: String, op: char| {
+        format!("({}{})", lhs, op)
+      })
+      .parse(stream)
+  }
+
+  #[test]
+  fn precedence() {
+    let Success { token, stream } = expression(b"1+2*3-4;").unwrap();
+    assert_eq!(token, "((1 + (2 * 3)) - 4)");
+    assert_eq!(stream, b";");
+
+    let Success { token, .. } = expression(b"2^3^4*5").unwrap();
+    assert_eq!(token, "((2 ^ (3 ^ 4)) * 5)");
+
+    let Success { token, .. } = expression(b"-2^3!+-1=0").unwrap();
+    assert_eq!(token, "(((-(2 ^ (3!))) + (-1)) = 0)");
+  }
+
+  #[test]
+  fn operand() {
+    let Parsed::Failure(context) = expression(b"1+2*") else {
+      panic!()
+    };
+    assert_eq!(
+      context.last(),
+      &FromAtom::Expression(ExpressionAtom::Operand { operator: b"*" })
+    );
+    assert_eq!(context.last().to_string(), "expected operand after [42]");
+  }
+
+  #[test]
+  fn non_associative() {
+    let Parsed::Failure(context) = expression(b"1=2=3") else {
+      panic!()
+    };
+    assert_eq!(
+      context.last(),
+      &FromAtom::Expression(ExpressionAtom::NonAssociative { operator: b"=" })
+    );
+  }
+}
@@ -37,7 +37,7 @@ where
       let mut tokens: [MaybeUninit<Parser::Token>; N] = MaybeUninit::uninit().assume_init();
 
       for (i, t) in tokens.iter_mut().enumerate() {
-        match self.parser.parse(stream) {
+        match self.parser.parse(stream.clone()) {
           Parsed::Success {
             token,
             stream: next,
@@ -48,6 +48,10 @@ where
           Parsed::Failure(context) => {
             return Parsed::Failure(context.add(UtilsAtom::MinNotReach { i, min: N }));
           }
+          // the stream ran out of Item but can still receive more
+          Parsed::Error(context) if stream.require(1).is_err() => {
+            return Parsed::Error(context.add(UtilsAtom::MinNotReach { i, min: N }));
+          }
           Parsed::Error(context) => {
             return Parsed::Error(context);
           }
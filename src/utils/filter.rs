@@ -3,6 +3,7 @@ use crate::{
   Contexting,
   Parse,
   Parsed,
+  Streaming,
 };
 
 /// Implementation of [crate::utils::Utils::filter]
@@ -14,6 +15,7 @@ pub struct Filter<Parser, F> {
 
 impl<Stream, Context, Parser, F> Parse<Stream, Context> for Filter<Parser, F>
 where
+  Stream: Streaming,
   Parser: Parse<Stream, Context>,
   F: Fn(&Parser::Token) -> bool,
   Context: Contexting<UtilsAtom<Stream>>,
@@ -21,11 +23,12 @@ where
   type Token = Parser::Token;
 
   fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    let cursor = stream.cursor();
     let success = self.parser.parse(stream)?;
     if (self.f)(&success.token) {
       success.into()
     } else {
-      Parsed::new_failure(Context::new(UtilsAtom::Filter))
+      Parsed::new_failure(Context::new_at(UtilsAtom::Filter, cursor))
     }
   }
 }
@@ -33,6 +36,7 @@ where
 /// Function style version of [crate::utils::Utils::filter]
 pub fn filter<Stream, Context, Parser, F>(parser: Parser, f: F) -> Filter<Parser, F>
 where
+  Stream: Streaming,
   Parser: Parse<Stream, Context>,
   F: Fn(&Parser::Token) -> bool,
   Context: Contexting<UtilsAtom<Stream>>,
@@ -3,6 +3,7 @@ use crate::{
   Contexting,
   Parse,
   Parsed,
+  Streaming,
 };
 
 /// Implementation of [crate::utils::Utils::filter_map]
@@ -14,6 +15,7 @@ pub struct FilterMap<Parser, F> {
 
 impl<Stream, Context, Parser, TokenSecond, F> Parse<Stream, Context> for FilterMap<Parser, F>
 where
+  Stream: Streaming,
   Parser: Parse<Stream, Context>,
   F: Fn(Parser::Token) -> Option<TokenSecond>,
   Context: Contexting<UtilsAtom<Stream>>,
@@ -21,11 +23,12 @@ where
   type Token = TokenSecond;
 
   fn parse(&mut self, stream: Stream) -> Parsed<TokenSecond, Stream, Context> {
+    let cursor = stream.cursor();
     let success = self.parser.parse(stream)?;
     if let Some(token) = (self.f)(success.token) {
       Parsed::new_success(token, success.stream)
     } else {
-      Parsed::new_failure(Context::new(UtilsAtom::Filter))
+      Parsed::new_failure(Context::new_at(UtilsAtom::Filter, cursor))
     }
   }
 }
@@ -35,6 +38,7 @@ pub fn filter_map<Stream, Context, Parser, TokenSecond, F>(
   parser: Parser, f: F,
 ) -> FilterMap<Parser, F>
 where
+  Stream: Streaming,
   Parser: Parse<Stream, Context>,
   F: Fn(Parser::Token) -> Option<TokenSecond>,
   Context: Contexting<UtilsAtom<Stream>>,
@@ -110,7 +110,7 @@ macro_rules! allow_failure {
 
 macro_rules! deny_failure {
   ($stream:expr, $parser:expr, $acc:expr, $fold:expr, $min:expr, $i:expr) => {{
-    match $parser.parse($stream) {
+    match $parser.parse($stream.clone()) {
       Parsed::Success { token, stream } => {
         $acc = $fold($acc, token);
         $stream = stream;
@@ -118,6 +118,10 @@ macro_rules! deny_failure {
       Parsed::Failure(context) => {
         return Parsed::Failure(context.add(UtilsAtom::MinNotReach { min: $min, i: $i }));
       }
+      // the stream ran out of Item but can still receive more
+      Parsed::Error(context) if $stream.require(1).is_err() => {
+        return Parsed::Error(context.add(UtilsAtom::MinNotReach { min: $min, i: $i }));
+      }
       Parsed::Error(context) => {
         return Parsed::Error(context);
       }
@@ -265,8 +269,7 @@ macro_rules! impl_primitive {
         &self, parser: &mut Parser, init: &mut Init, f: &mut F, mut stream: Stream,
       ) -> Parsed<Acc, Stream, Context>
       where
-        Stream: Clone,
-        Stream: Eq,
+        Stream: Streaming,
         Context: Contexting<UtilsAtom<Stream>>,
         Parser: Parse<Stream, Context>,
         Init: FnMut() -> Acc,
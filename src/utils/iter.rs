@@ -0,0 +1,107 @@
+use core::convert::Infallible;
+
+use crate::{
+  Parse,
+  Parsed,
+};
+
+/// Implementation of [crate::utils::Utils::iter]
+#[derive(Clone)]
+pub struct ParseIter<Parser, Stream, Context> {
+  parser: Parser,
+  stream: Stream,
+  end: Option<Parsed<Infallible, Stream, Context>>,
+}
+
+impl<Parser, Stream, Context> ParseIter<Parser, Stream, Context> {
+  /// Return the stream left by the last successful call of parser
+  pub const fn stream(&self) -> &Stream {
+    &self.stream
+  }
+
+  /// Return the Failure or Error that ended the iteration, None if the
+  /// iteration is not over
+  pub const fn end(&self) -> Option<&Parsed<Infallible, Stream, Context>> {
+    self.end.as_ref()
+  }
+
+  /// Return the stream left and the Failure or Error that ended the
+  /// iteration
+  pub fn into_inner(self) -> (Stream, Option<Parsed<Infallible, Stream, Context>>) {
+    (self.stream, self.end)
+  }
+}
+
+impl<Parser, Stream, Context> Iterator for ParseIter<Parser, Stream, Context>
+where
+  Parser: Parse<Stream, Context>,
+  Stream: Clone,
+{
+  type Item = Parser::Token;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    if self.end.is_some() {
+      return None;
+    }
+    match self.parser.parse(self.stream.clone()) {
+      Parsed::Success { token, stream } => {
+        self.stream = stream;
+        Some(token)
+      }
+      Parsed::Failure(context) => {
+        self.end = Some(Parsed::Failure(context));
+        None
+      }
+      Parsed::Error(context) => {
+        self.end = Some(Parsed::Error(context));
+        None
+      }
+    }
+  }
+}
+
+/// Function style version of [crate::utils::Utils::iter]
+pub const fn iter<Stream, Context, Parser>(
+  parser: Parser, stream: Stream,
+) -> ParseIter<Parser, Stream, Context>
+where
+  Parser: Parse<Stream, Context>,
+{
+  ParseIter {
+    parser,
+    stream,
+    end: None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{
+    base::primitive::u16_be,
+    context::Ignore,
+    stream::Partial,
+    utils::Utils,
+    Parsed,
+  };
+
+  #[test]
+  fn records() {
+    let stream = &b"\x00\x01\x00\x02\x00\x03!"[..];
+    let mut records = Utils::<_, Ignore>::iter(u16_be, stream);
+    assert!(records.by_ref().eq([1, 2, 3]));
+    assert_eq!(records.stream(), &b"!");
+    assert_eq!(records.end(), Some(&Parsed::Failure(Ignore)));
+    assert_eq!(records.next(), None);
+  }
+
+  #[test]
+  fn error() {
+    let stream = Partial::new(&b"\x00\x01\x00"[..]);
+    let mut records = Utils::<_, Ignore>::iter(u16_be, stream);
+    assert_eq!(records.next(), Some(1));
+    assert_eq!(records.next(), None);
+    let (stream, end) = records.into_inner();
+    assert_eq!(stream.into_inner(), b"\x00");
+    assert_eq!(end, Some(Parsed::Error(Ignore)));
+  }
+}
@@ -0,0 +1,60 @@
+use crate::{
+  Parse,
+  Parsed,
+};
+
+/// Implementation of [lazy]
+#[derive(Clone)]
+pub struct Lazy<F, Parser> {
+  f: F,
+  parser: Option<Parser>,
+}
+
+impl<Stream, Context, F, Parser> Parse<Stream, Context> for Lazy<F, Parser>
+where
+  F: FnMut() -> Parser,
+  Parser: Parse<Stream, Context>,
+{
+  type Token = Parser::Token;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    self.parser.get_or_insert_with(&mut self.f).parse(stream)
+  }
+}
+
+/// Build the parser with f only the first time it's used, then the same
+/// parser is reused. This allow to delay the construction of a costly parser
+/// or a parser that can only be built later. For a parser that refer to
+/// itself use [crate::utils::recursive].
+pub const fn lazy<Stream, Context, F, Parser>(f: F) -> Lazy<F, Parser>
+where
+  F: FnMut() -> Parser,
+  Parser: Parse<Stream, Context>,
+{
+  Lazy { f, parser: None }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::lazy;
+  use crate::{
+    base::is,
+    context::Ignore,
+    Parse,
+    Parsed,
+  };
+
+  #[test]
+  fn build_once() {
+    let mut built = 0_usize;
+    let mut parser = lazy(|| {
+      built += 1;
+      is('a')
+    });
+    let parsed: Parsed<_, _, Ignore> = parser.parse("ab");
+    assert_eq!(parsed, Parsed::new_success('a', "b"));
+    let parsed: Parsed<_, _, Ignore> = parser.parse("b");
+    assert_eq!(parsed, Parsed::Failure(Ignore));
+    assert_eq!(built, 1);
+  }
+}
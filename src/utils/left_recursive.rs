@@ -0,0 +1,253 @@
+use core::cell::RefCell;
+
+use crate::{
+  utils::{
+    recursive,
+    MemoTable,
+    Recursive,
+    UtilsAtom,
+  },
+  Contexting,
+  Parse,
+  Parsed,
+  StreamPosition,
+};
+
+/// Implementation of [left_recursive], it's also the parser given to the
+/// closure to refer to itself.
+pub struct LeftRecursive<'a, Stream, Context, Token> {
+  parser: Recursive<'a, Stream, Context, Token>,
+  table: &'a RefCell<MemoTable<Token, Stream, Context>>,
+  id: usize,
+}
+
+impl<'a, Stream, Context, Token> Clone for LeftRecursive<'a, Stream, Context, Token> {
+  fn clone(&self) -> Self {
+    Self {
+      parser: self.parser.clone(),
+      table: self.table,
+      id: self.id,
+    }
+  }
+}
+
+impl<'a, Stream, Context, Token> LeftRecursive<'a, Stream, Context, Token> {
+  /// Same than [Recursive::max_depth], only the recursion that consume the
+  /// stream are counted.
+  pub fn max_depth(self, max: usize) -> Self {
+    Self {
+      parser: self.parser.max_depth(max),
+      table: self.table,
+      id: self.id,
+    }
+  }
+}
+
+impl<'a, Stream, Context, Token> Parse<Stream, Context>
+  for LeftRecursive<'a, Stream, Context, Token>
+where
+  Stream: StreamPosition,
+  Context: Contexting<UtilsAtom<Stream>> + Clone,
+  Token: Clone,
+{
+  type Token = Token;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    // already parsed or called by itself at the same position
+    if let Some(parsed) = self.table.borrow().get(self.id, &stream) {
+      return parsed;
+    }
+
+    // the seed is a Failure, so the first time only the alternatives that
+    // doesn't start by the rule can succeed, then the rule is parsed again
+    // with the previous result until it doesn't consume more
+    let mut best = Parsed::Failure(Context::new(UtilsAtom::LeftRecursion));
+    self
+      .table
+      .borrow_mut()
+      .insert(self.id, stream.clone(), best.clone());
+    loop {
+      let parsed = self.parser.parse(stream.clone());
+      let grow = match (&best, &parsed) {
+        (
+          Parsed::Success {
+            stream: stream_best,
+            ..
+          },
+          Parsed::Success {
+            stream: stream_success,
+            ..
+          },
+        ) => stream_best != stream_success && stream_best.clone().diff(stream_success).is_ok(),
+        (Parsed::Success { .. }, Parsed::Failure(_)) => false,
+        // first result or Error
+        _ => true,
+      };
+      if !grow {
+        break;
+      }
+
+      let end = !parsed.is_success();
+      best = parsed;
+      if end {
+        break;
+      }
+      self
+        .table
+        .borrow_mut()
+        .insert(self.id, stream.clone(), best.clone());
+    }
+
+    self
+      .table
+      .borrow_mut()
+      .insert(self.id, stream, best.clone());
+    best
+  }
+}
+
+/// Return a parser that can refer to itself at the start of the rule like
+/// `expr = expr '-' term | term`. The result of the rule for each position is
+/// kept in table, the table must only be used for one input.
+/// When the rule refer to itself at the same position it receive the last
+/// result found, starting from a Failure, the rule is repeated as long as it
+/// consume more than before. Only direct left recursion is supported, the
+/// rule must refer to itself at its start, two left_recursive rules that refer
+/// to each other at the same position (indirect left recursion) are not
+/// handled. Many rules can share one table.
+pub fn left_recursive<'a, Stream, Context, Token, Parser, F>(
+  table: &'a RefCell<MemoTable<Token, Stream, Context>>, f: F,
+) -> LeftRecursive<'a, Stream, Context, Token>
+where
+  Stream: StreamPosition,
+  Context: Contexting<UtilsAtom<Stream>> + Clone,
+  Token: Clone,
+  Parser: Parse<Stream, Context, Token = Token> + 'a,
+  F: Fn(LeftRecursive<'a, Stream, Context, Token>) -> Parser + 'a,
+{
+  let id = table.borrow_mut().id();
+  LeftRecursive {
+    parser: recursive(move |parser| f(LeftRecursive { parser, table, id })),
+    table,
+    id,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::cell::RefCell;
+
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use super::left_recursive;
+  use crate::{
+    base::{
+      is,
+      octet,
+      BaseAtom,
+    },
+    context::{
+      Keep,
+      Last,
+    },
+    utils::{
+      MemoTable,
+      Utils,
+      UtilsAtom,
+    },
+    CoreAtom,
+    Parse,
+    Parsed,
+    Success,
+  };
+
+  type Stream = &'static [u8];
+
+  #[derive(Display, Debug, Clone, From, PartialEq)]
+  enum FromAtom {
+    Utils(UtilsAtom<Stream>),
+    Base(BaseAtom<u8>),
+    Core(CoreAtom<Stream>),
+  }
+
+  type HandleAtom = Keep<Last, FromAtom>;
+
+  fn digit(stream: Stream) -> Parsed<i64, Stream, HandleAtom> {
+    octet
+      .filter(u8::is_ascii_digit)
+      .map(|digit| i64::from(digit - b'0'))
+      .parse(stream)
+  }
+
+  // expr = expr '-' term | term
+  // term = digit | '(' expr ')'
+  fn expr(stream: Stream) -> Parsed<i64, Stream, HandleAtom> {
+    let table = RefCell::new(MemoTable::new());
+    let mut expr = left_recursive(&table, |expr| {
+      let inner = expr.clone();
+      let term = move || digit.or(is(b'(').drop_and(inner.clone()).and_drop(is(b')')));
+      expr
+        .and_drop(is(b'-'))
+        .and(term())
+        .map(|(lhs, rhs)| lhs - rhs)
+        .or(term())
+    });
+    expr.parse(stream)
+  }
+
+  // expr = digit ('-' digit)*
+  fn fold(stream: Stream) -> Parsed<i64, Stream, HandleAtom> {
+    let Success { token, stream } = digit.parse(stream)?;
+    is(b'-')
+      .drop_and(digit)
+      .fold_bounds(.., || token, |lhs, rhs| lhs - rhs)
+      .parse(stream)
+  }
+
+  // expr = expr '-' term | term
+  // term = term '*' digit | digit
+  fn two_rules(stream: Stream) -> Parsed<i64, Stream, HandleAtom> {
+    let table = RefCell::new(MemoTable::new());
+    let term = left_recursive(&table, |term| {
+      term
+        .and_drop(is(b'*'))
+        .and(digit)
+        .map(|(lhs, rhs)| lhs * rhs)
+        .or(digit)
+    });
+    let mut expr = left_recursive(&table, move |expr| {
+      expr
+        .and_drop(is(b'-'))
+        .and(term.clone())
+        .map(|(lhs, rhs)| lhs - rhs)
+        .or(term.clone())
+    });
+    expr.parse(stream)
+  }
+
+  #[test]
+  fn shared_table() {
+    let Success { token, stream } = two_rules(b"8-2*3-1;").unwrap();
+    assert_eq!(token, 1);
+    assert_eq!(stream, b";");
+
+    assert_eq!(two_rules(b"2*3*4").unwrap().token, 24);
+    assert_eq!(two_rules(b"9-2*2*2").unwrap().token, 1);
+  }
+
+  #[test]
+  fn seed_growing() {
+    for stream in [&b"1-2-3;"[..], b"9", b"8-1-", b"7-1-1-1-1-1-1"] {
+      assert_eq!(expr(stream), fold(stream));
+    }
+
+    let Success { token, stream } = expr(b"8-(3-2)-1;").unwrap();
+    assert_eq!(token, 6);
+    assert_eq!(stream, b";");
+
+    assert!(matches!(expr(b"-1"), Parsed::Failure(_)));
+  }
+}
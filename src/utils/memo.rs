@@ -0,0 +1,231 @@
+use alloc::collections::BTreeMap;
+use core::{
+  cell::RefCell,
+  fmt::{
+    self,
+    Debug,
+    Formatter,
+  },
+};
+
+use crate::{
+  Parse,
+  Parsed,
+  StreamPosition,
+};
+
+// id of the parser and offset of the stream
+type Key = (usize, usize);
+
+/// Table used by [crate::utils::Utils::memo] to keep the result of parsers
+/// for each position of the stream. Every parser given to memo have its own
+/// results in the table, a clone of a memo parser share them. A table must
+/// only be used with one input, clear it before parse another input.
+pub struct MemoTable<Token, Stream, Context> {
+  map: BTreeMap<Key, (Stream, Parsed<Token, Stream, Context>)>,
+  ids: usize,
+}
+
+impl<Token, Stream, Context> MemoTable<Token, Stream, Context> {
+  /// Return a new empty table
+  pub const fn new() -> Self {
+    Self {
+      map: BTreeMap::new(),
+      ids: 0,
+    }
+  }
+
+  /// Return the number of result in the table
+  pub fn len(&self) -> usize {
+    self.map.len()
+  }
+
+  /// Return true if the table doesn't contain any result
+  pub fn is_empty(&self) -> bool {
+    self.map.is_empty()
+  }
+
+  /// Remove all result of the table, parsers that use it can still be used
+  pub fn clear(&mut self) {
+    self.map.clear()
+  }
+
+  // id of a new parser that use the table
+  pub(crate) const fn id(&mut self) -> usize {
+    let id = self.ids;
+    self.ids += 1;
+    id
+  }
+}
+
+impl<Token, Stream, Context> MemoTable<Token, Stream, Context>
+where
+  Stream: StreamPosition,
+  Token: Clone,
+  Context: Clone,
+{
+  // the stream is compared too, so a stream with the same offset but
+  // different, like a Span or a different State, is not mistaken
+  pub(crate) fn get(&self, id: usize, stream: &Stream) -> Option<Parsed<Token, Stream, Context>> {
+    match self.map.get(&(id, stream.offset())) {
+      Some((memo, parsed)) if memo == stream => Some(parsed.clone()),
+      _ => None,
+    }
+  }
+
+  pub(crate) fn insert(
+    &mut self, id: usize, stream: Stream, parsed: Parsed<Token, Stream, Context>,
+  ) {
+    self.map.insert((id, stream.offset()), (stream, parsed));
+  }
+}
+
+impl<Token, Stream, Context> Default for MemoTable<Token, Stream, Context> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<Token, Stream, Context> Debug for MemoTable<Token, Stream, Context> {
+  fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+    f.debug_struct("MemoTable")
+      .field("len", &self.map.len())
+      .finish()
+  }
+}
+
+/// Implementation of [crate::utils::Utils::memo]
+pub struct Memo<'t, Parser, Token, Stream, Context> {
+  parser: Parser,
+  table: &'t RefCell<MemoTable<Token, Stream, Context>>,
+  id: usize,
+}
+
+impl<'t, Parser: Clone, Token, Stream, Context> Clone for Memo<'t, Parser, Token, Stream, Context> {
+  fn clone(&self) -> Self {
+    Self {
+      parser: self.parser.clone(),
+      table: self.table,
+      id: self.id,
+    }
+  }
+}
+
+impl<'t, Parser, Token, Stream, Context> Parse<Stream, Context>
+  for Memo<'t, Parser, Token, Stream, Context>
+where
+  Stream: StreamPosition,
+  Context: Clone,
+  Parser: Parse<Stream, Context, Token = Token>,
+  Token: Clone,
+{
+  type Token = Token;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    if let Some(parsed) = self.table.borrow().get(self.id, &stream) {
+      return parsed;
+    }
+
+    // the table must not be borrowed while parsing, parser can use it too
+    let parsed = self.parser.parse(stream.clone());
+    self
+      .table
+      .borrow_mut()
+      .insert(self.id, stream, parsed.clone());
+    parsed
+  }
+}
+
+/// Function style version of [crate::utils::Utils::memo]
+pub fn memo<'t, Stream, Context, Parser>(
+  parser: Parser, table: &'t RefCell<MemoTable<Parser::Token, Stream, Context>>,
+) -> Memo<'t, Parser, Parser::Token, Stream, Context>
+where
+  Stream: StreamPosition,
+  Context: Clone,
+  Parser: Parse<Stream, Context>,
+  Parser::Token: Clone,
+{
+  Memo {
+    parser,
+    id: table.borrow_mut().id(),
+    table,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+  use core::cell::{
+    Cell,
+    RefCell,
+  };
+
+  use super::MemoTable;
+  use crate::{
+    base::{
+      is,
+      octet,
+    },
+    context::Ignore,
+    utils::{
+      Acc,
+      Utils,
+    },
+    Parse,
+    Parsed,
+    Success,
+  };
+
+  type Stream = &'static [u8];
+
+  #[test]
+  fn backtrack() {
+    let called = Cell::new(0_usize);
+    let digits = |stream: Stream| -> Parsed<_, _, Ignore> {
+      called.set(called.get() + 1);
+      octet
+        .filter(u8::is_ascii_digit)
+        .fold_bounds(1.., Vec::new, Acc::acc)
+        .parse(stream)
+    };
+
+    let table = RefCell::new(MemoTable::new());
+    // all alternatives start by the same digits
+    let digits = digits.memo(&table);
+    let mut parser = [
+      digits.clone().and_drop(is(b'+')),
+      digits.clone().and_drop(is(b'-')),
+      digits.and_drop(is(b';')),
+    ];
+    let Success { token, stream } = parser.parse(&b"123;"[..]).unwrap();
+    assert_eq!(token, b"123");
+    assert_eq!(stream, b"");
+    assert_eq!(called.get(), 1);
+    assert_eq!(table.borrow().len(), 1);
+
+    // the Span of the same position is not mistaken for the stream
+    let stream = &b"456;"[..];
+    let span = &stream[..2];
+    table.borrow_mut().clear();
+    let parsed = parser[0].parse(span);
+    assert_eq!(parsed, Parsed::Failure(Ignore));
+    let parsed = parser[2].parse(stream);
+    assert_eq!(parsed.unwrap().token, b"456");
+    assert_eq!(called.get(), 3);
+  }
+
+  #[test]
+  fn parsers_share_table() {
+    let table = RefCell::new(MemoTable::new());
+    let stream = &b"ab"[..];
+    let parsed: Parsed<_, _, Ignore> = is(b'a').memo(&table).parse(stream);
+    assert_eq!(parsed.unwrap().token, b'a');
+    // the result of the first parser is not returned at the same position
+    let parsed = is(b'b').memo(&table).parse(stream);
+    assert_eq!(parsed, Parsed::Failure(Ignore));
+    let parsed = octet.memo(&table).drop_and(is(b'b')).parse(stream);
+    assert_eq!(parsed.unwrap().token, b'b');
+    assert_eq!(table.borrow().len(), 3);
+  }
+}
@@ -4,6 +4,8 @@
 //!
 //! [Utils] trait contain everything you want to know
 
+#[cfg(feature = "alloc")]
+use core::cell::RefCell;
 use core::{
   fmt::{
     Debug,
@@ -16,6 +18,8 @@ use core::{
   },
 };
 
+#[cfg(feature = "alloc")]
+use crate::StreamPosition;
 use crate::{
   Contexting,
   Parse,
@@ -37,6 +41,12 @@ mod drop_and;
 pub use drop_and::*;
 mod or;
 pub use or::*;
+mod cut;
+pub use cut::*;
+mod commit;
+pub use commit::*;
+mod delimited;
+pub use delimited::*;
 mod not;
 pub use not::*;
 mod peek;
@@ -71,11 +81,39 @@ mod try_fold_until;
 pub use try_fold_until::*;
 mod fill;
 pub use fill::*;
+mod separated;
+pub use separated::*;
+mod expression;
+pub use expression::*;
+mod skip_until;
+pub use skip_until::*;
+#[cfg(feature = "alloc")]
+mod recover;
+#[cfg(feature = "alloc")]
+pub use recover::*;
 
 mod enumerate;
 pub use enumerate::*;
 mod limit;
 pub use limit::*;
+mod bounded;
+pub use bounded::*;
+mod iter;
+pub use iter::*;
+#[cfg(feature = "alloc")]
+mod memo;
+#[cfg(feature = "alloc")]
+pub use memo::*;
+mod lazy;
+pub use lazy::*;
+#[cfg(feature = "alloc")]
+mod recursive;
+#[cfg(feature = "alloc")]
+pub use recursive::*;
+#[cfg(feature = "alloc")]
+mod left_recursive;
+#[cfg(feature = "alloc")]
+pub use left_recursive::*;
 
 mod add_atom;
 pub use add_atom::*;
@@ -109,8 +147,20 @@ pub enum UtilsAtom<Stream> {
   /// When max combinator reached the max allowed.
   // Stand alone ?
   Max(usize),
+  /// When recursive parser exceeded the max depth allowed
+  MaxDepth(usize),
+  /// Seed of left_recursive parser, when the rule refer to itself for the
+  /// first time at a position
+  LeftRecursion,
   /// When filter combinator return failure if filter refuse the Token
   Filter,
+  /// When separated combinator found a separator not followed by an item and
+  /// trailing separator are denied
+  TrailingSeparator,
+  /// When parser given to bounded combinator didn't consume all items
+  Leftover,
+  /// When length_value combinator can't convert the length to usize
+  Length,
   /// When Span combinator call diff from stream but it's return Error.
   /// If you encounter this, it's either mean the two stream are not the same or
   /// you rewind the stream to a previous point of original stream
@@ -130,7 +180,12 @@ impl<Stream> Display for UtilsAtom<Stream> {
       UtilsAtom::UntilNotReach => write!(f, "UntilNotReach"),
       //      UtilsAtom::IterEndNotReach => write!(f, "IterEndNotReach"),
       UtilsAtom::Max(n) => write!(f, "Max {}", n),
+      UtilsAtom::MaxDepth(n) => write!(f, "MaxDepth {}", n),
+      UtilsAtom::LeftRecursion => write!(f, "LeftRecursion"),
       UtilsAtom::Filter { .. } => write!(f, "Filter"),
+      UtilsAtom::TrailingSeparator => write!(f, "TrailingSeparator"),
+      UtilsAtom::Leftover => write!(f, "Leftover"),
+      UtilsAtom::Length => write!(f, "Length"),
       UtilsAtom::Diff { .. } => write!(f, "Diff"),
     }
   }
@@ -163,6 +218,41 @@ where
     and(self, other)
   }
 
+  /// Same than and combinator but once the underline parser is successful the
+  /// Failure of the parser given in parameter is turned into an Error. Use it
+  /// when the first parser is enough to know the right branch was chosen.
+  fn commit<OtherParser, OtherToken>(self, other: OtherParser) -> Commit<Self, OtherParser>
+  where
+    OtherParser: Parse<Stream, Context, Token = OtherToken>,
+  {
+    commit(self, other)
+  }
+
+  /// Call open, the underline parser and close, returning only the Token of
+  /// the underline parser. If close doesn't succeed an UnclosedAtom with the
+  /// Span of open is added to the context, so the user know where the
+  /// delimiter was opened.
+  fn delimited<Open, Close>(self, open: Open, close: Close) -> Delimited<Open, Self, Close>
+  where
+    Open: Parse<Stream, Context>,
+    Close: Parse<Stream, Context>,
+    Context: Contexting<UtilsAtom<Stream>>,
+    Context: Contexting<UnclosedAtom<Stream>>,
+  {
+    delimited(open, self, close)
+  }
+
+  /// Same than delimited combinator but quote is used to open and to close,
+  /// like a string between `"`.
+  fn surrounded_by<Quote>(self, quote: Quote) -> SurroundedBy<Self, Quote>
+  where
+    Quote: Parse<Stream, Context>,
+    Context: Contexting<UtilsAtom<Stream>>,
+    Context: Contexting<UnclosedAtom<Stream>>,
+  {
+    surrounded_by(self, quote)
+  }
+
   /// Same than and combinator but it will drop the second Token instead,
   /// returning only the first Token from the inner parser.
   fn and_drop<OtherParser, OtherToken>(self, other: OtherParser) -> AndDrop<Self, OtherParser>
@@ -181,12 +271,39 @@ where
     drop_and(self, other)
   }
 
+  /// Turn a Failure of the underline parser into an Error. Combinator like
+  /// or, array or fold_bounds will not try other branch and the Error of this
+  /// parser will be returned.
+  fn cut(self) -> Cut<Self> {
+    cut(self)
+  }
+
   /// Call the underline parser but drop the Token if sucessful. This can be
   /// considered as a shortcut of the toilet closure: `.map(|_| ())`.
   fn drop(self) -> Drop<Self> {
     drop(self)
   }
 
+  /// Parse an expression where the underline parser is the operand and infix
+  /// produce the operator, its binding power and its associativity. Operator
+  /// with higher binding power bind first, `1 + 2 * 3` is `1 + (2 * 3)` if `*`
+  /// have a higher binding power than `+`. fold is called with the left
+  /// operand, the operator and the right operand. Prefix and postfix operator
+  /// can be added with [Expression::prefix] and [Expression::postfix]. When an
+  /// operator is not followed by an operand an ExpressionAtom is added to the
+  /// context.
+  fn expression<Infix, InfixOp, F>(
+    self, infix: Infix, fold: F,
+  ) -> InfixExpression<Stream, Context, Self, Infix, F>
+  where
+    Context: Contexting<UtilsAtom<Stream>>,
+    Context: Contexting<ExpressionAtom<Stream>>,
+    Infix: Parse<Stream, Context, Token = (InfixOp, u8, Associativity)>,
+    F: FnMut(Self::Token, InfixOp, Self::Token) -> Self::Token,
+  {
+    expression(self, infix, fold)
+  }
+
   /// Will call the underline parser N times to fill an array of size N and
   /// return [Token; N] if successfull
   fn fill<const N: usize>(self) -> Fill<Self, N>
@@ -281,6 +398,59 @@ where
     fold_bounds(self, bounds, init, f)
   }
 
+  /// Same than fold_bounds but the underline parser must be separated by sep
+  /// parser, like `item (sep item)*`. Bounds apply on the number of items. A
+  /// separator not followed by an item is left in the stream, see
+  /// [Separated::trailing] to change this.
+  fn separated<Sep, Bounds, Acc, Init, F>(
+    self, sep: Sep, bounds: Bounds, init: Init, f: F,
+  ) -> Separated<Self, Sep, Bounds, Init, F>
+  where
+    Context: Contexting<UtilsAtom<Stream>>,
+    Sep: Parse<Stream, Context>,
+    Init: FnMut() -> Acc,
+    F: FnMut(Acc, Self::Token) -> Acc,
+    Bounds: FoldBoundsParse,
+    Acc: Debug,
+  {
+    separated(self, sep, bounds, init, f)
+  }
+
+  /// Same than separated but a separator after the last item is consumed.
+  fn separated_trailing<Sep, Bounds, Acc, Init, F>(
+    self, sep: Sep, bounds: Bounds, init: Init, f: F,
+  ) -> Separated<Self, Sep, Bounds, Init, F>
+  where
+    Context: Contexting<UtilsAtom<Stream>>,
+    Sep: Parse<Stream, Context>,
+    Init: FnMut() -> Acc,
+    F: FnMut(Acc, Self::Token) -> Acc,
+    Bounds: FoldBoundsParse,
+    Acc: Debug,
+  {
+    separated_trailing(self, sep, bounds, init, f)
+  }
+
+  /// Same than fold_bounds but when the underline parser fail, its context
+  /// is saved and recovery parser is called to resynchronize the stream, like
+  /// [skip_until]. F receive None for the Token of a failure recovered. The
+  /// Token produced contains the accumulator and the contexts of all failures
+  /// recovered.
+  #[cfg(feature = "alloc")]
+  fn fold_bounds_recovering<Bounds, Recovery, Acc, Init, F>(
+    self, bounds: Bounds, recovery: Recovery, init: Init, f: F,
+  ) -> FoldBoundsRecovering<Self, Recovery, Bounds, Init, F>
+  where
+    Context: Contexting<UtilsAtom<Stream>>,
+    Recovery: Parse<Stream, Context>,
+    Init: FnMut() -> Acc,
+    F: FnMut(Acc, Option<Self::Token>) -> Acc,
+    Bounds: FoldBoundsParse,
+    Acc: Debug,
+  {
+    fold_bounds_recovering(self, bounds, recovery, init, f)
+  }
+
   /// Same than fold_bounds but F and Acc can return type that implement Try
   fn try_fold_bounds<Bounds, Acc, Init, Ret, F>(
     self, bounds: Bounds, init: Init, f: F,
@@ -420,6 +590,45 @@ where
     span(self)
   }
 
+  /// Return an iterator that call the underline parser on stream until it
+  /// doesn't return Success, the Token are the items of the iterator. The
+  /// stream left and the Failure or Error that ended the iteration are
+  /// available on the iterator. This allow to process Token lazily instead of
+  /// collect them with fold_bounds. Be aware that a parser that doesn't consume
+  /// the stream will produce Token forever.
+  fn iter(self, stream: Stream) -> ParseIter<Self, Stream, Context> {
+    iter(self, stream)
+  }
+
+  /// Keep the result of the underline parser in table for each position of
+  /// the stream, when the parser is called again at the same position the
+  /// result is cloned from the table instead of parse again. This make
+  /// alternatives that backtrack a lot linear. Clone the memo parser to use
+  /// it at many places, the table can be shared by different parsers.
+  #[cfg(feature = "alloc")]
+  fn memo(
+    self, table: &RefCell<MemoTable<Self::Token, Stream, Context>>,
+  ) -> Memo<'_, Self, Self::Token, Stream, Context>
+  where
+    Stream: StreamPosition,
+    Context: Clone,
+    Self::Token: Clone,
+  {
+    memo(self, table)
+  }
+
+  /// If the underline parser fail, recovery parser is called on the same
+  /// stream to resynchronize it, like [skip_until]. The Token produced is None
+  /// in this case and the context of the failure is saved in the Token.
+  /// Recovery parser must consume at least one item. Error are not recovered.
+  #[cfg(feature = "alloc")]
+  fn recover_with<Recovery>(self, recovery: Recovery) -> RecoverWith<Self, Recovery>
+  where
+    Recovery: Parse<Stream, Context>,
+  {
+    recover_with(self, recovery)
+  }
+
   /// Same than .filter_map() but expect an Atom in case of Failure.
   fn try_map<OtherToken, F, Ret>(self, f: F) -> TryMap<Self, F>
   where
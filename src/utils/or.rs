@@ -24,10 +24,10 @@ where
   fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
     match self.a.parse(stream.clone()) {
       success @ Parsed::Success { .. } => success,
-      Parsed::Failure(context_a) => self
-        .b
-        .parse(stream)
-        .map_context(|context_b| context_a.bitor(context_b)),
+      Parsed::Failure(context_a) => match self.b.parse(stream) {
+        Parsed::Failure(context_b) => Parsed::Failure(context_a.bitor(context_b)),
+        parsed => parsed,
+      },
       Parsed::Error(context) => Parsed::Error(context),
     }
   }
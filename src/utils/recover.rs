@@ -0,0 +1,247 @@
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use crate::{
+  utils::{
+    FoldBoundsParse,
+    UtilsAtom,
+  },
+  Contexting,
+  Parse,
+  Parsed,
+  Streaming,
+};
+
+/// Token produced by recovering combinator, contains the contexts of the
+/// failures that were recovered
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recovered<Token, Context> {
+  /// The Token produced
+  pub token: Token,
+  /// Contexts of the failures recovered, from first to last
+  pub errors: Vec<Context>,
+}
+
+/// Implementation of [crate::utils::Utils::recover_with]
+#[derive(Clone)]
+pub struct RecoverWith<Parser, Recovery> {
+  parser: Parser,
+  recovery: Recovery,
+}
+
+impl<Stream, Context, Parser, Recovery> Parse<Stream, Context> for RecoverWith<Parser, Recovery>
+where
+  Stream: Eq + Clone,
+  Parser: Parse<Stream, Context>,
+  Recovery: Parse<Stream, Context>,
+{
+  type Token = Recovered<Option<Parser::Token>, Context>;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    match self.parser.parse(stream.clone()) {
+      Parsed::Success { token, stream } => Parsed::Success {
+        token: Recovered {
+          token: Some(token),
+          errors: Vec::new(),
+        },
+        stream,
+      },
+      Parsed::Failure(context) => match self.recovery.parse(stream.clone()) {
+        // recovery must consume something or it would loop forever
+        Parsed::Success {
+          token: _,
+          stream: recovered,
+        } if recovered != stream => Parsed::Success {
+          token: Recovered {
+            token: None,
+            errors: alloc::vec![context],
+          },
+          stream: recovered,
+        },
+        Parsed::Success { .. } | Parsed::Failure(_) => Parsed::Failure(context),
+        Parsed::Error(context) => Parsed::Error(context),
+      },
+      Parsed::Error(context) => Parsed::Error(context),
+    }
+  }
+}
+
+/// Function style version of [crate::utils::Utils::recover_with]
+pub const fn recover_with<Stream, Context, Parser, Recovery>(
+  parser: Parser, recovery: Recovery,
+) -> RecoverWith<Parser, Recovery>
+where
+  Stream: Eq + Clone,
+  Parser: Parse<Stream, Context>,
+  Recovery: Parse<Stream, Context>,
+{
+  RecoverWith { parser, recovery }
+}
+
+/// Implementation of [crate::utils::Utils::fold_bounds_recovering]
+#[derive(Clone)]
+pub struct FoldBoundsRecovering<Parser, Recovery, Bounds, Init, F> {
+  parser: RecoverWith<Parser, Recovery>,
+  bounds: Bounds,
+  init: Init,
+  f: F,
+}
+
+impl<Bounds, Stream, Context, Parser, Recovery, Acc, Init, F> Parse<Stream, Context>
+  for FoldBoundsRecovering<Parser, Recovery, Bounds, Init, F>
+where
+  Stream: Streaming,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Parser: Parse<Stream, Context>,
+  Recovery: Parse<Stream, Context>,
+  Init: FnMut() -> Acc,
+  F: FnMut(Acc, Option<Parser::Token>) -> Acc,
+  Bounds: FoldBoundsParse,
+  Acc: Debug,
+{
+  type Token = Recovered<Acc, Context>;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    let (init, f) = (&mut self.init, &mut self.f);
+    self.bounds.fold_bounds(
+      &mut self.parser,
+      &mut || Recovered {
+        token: init(),
+        errors: Vec::new(),
+      },
+      &mut |mut acc: Recovered<Acc, Context>, item: Recovered<_, Context>| {
+        acc.token = f(acc.token, item.token);
+        acc.errors.extend(item.errors);
+        acc
+      },
+      stream,
+    )
+  }
+}
+
+/// Function style version of [crate::utils::Utils::fold_bounds_recovering]
+pub const fn fold_bounds_recovering<Bounds, Stream, Context, Parser, Recovery, Acc, Init, F>(
+  parser: Parser, bounds: Bounds, recovery: Recovery, init: Init, fold: F,
+) -> FoldBoundsRecovering<Parser, Recovery, Bounds, Init, F>
+where
+  Stream: Streaming,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Parser: Parse<Stream, Context>,
+  Recovery: Parse<Stream, Context>,
+  Init: FnMut() -> Acc,
+  F: FnMut(Acc, Option<Parser::Token>) -> Acc,
+  Bounds: FoldBoundsParse,
+  Acc: Debug,
+{
+  FoldBoundsRecovering {
+    parser: RecoverWith { parser, recovery },
+    bounds,
+    init,
+    f: fold,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use crate::{
+    base::{
+      end_of_stream,
+      is,
+      is_not,
+      BaseAtom,
+      EndOfStreamAtom,
+    },
+    context::{
+      Keep,
+      Last,
+    },
+    utils::{
+      skip_until,
+      Acc,
+      Utils,
+      UtilsAtom,
+    },
+    CoreAtom,
+    Parse,
+    Parsed,
+    ProvideElement,
+  };
+
+  type Stream = &'static str;
+
+  #[derive(Display, Debug, Clone, From, PartialEq)]
+  enum FromAtom {
+    Base(BaseAtom<char>),
+    Core(CoreAtom<Stream>),
+    Utils(UtilsAtom<Stream>),
+    #[display(fmt = "{:?}", _0)]
+    EndOfStream(EndOfStreamAtom<Stream>),
+  }
+
+  type HandleAtom = Keep<Last, FromAtom>;
+
+  #[derive(Debug, PartialEq)]
+  enum Entry {
+    Key(char),
+    Invalid,
+  }
+
+  fn sync(stream: Stream) -> Parsed<(), Stream, HandleAtom> {
+    is('\n').drop().or(end_of_stream).parse(stream)
+  }
+
+  // an entry is one char followed by ;
+  fn entry(stream: Stream) -> Parsed<Entry, Stream, HandleAtom> {
+    is_not('\n')
+      .and_drop(is(';'))
+      .and_drop(is('\n'))
+      .map(Entry::Key)
+      .parse(stream)
+  }
+
+  #[test]
+  fn recover_with() {
+    let parsed = entry.recover_with(skip_until(sync)).parse("ab;\nc;\n");
+    let recovered = parsed.unwrap();
+    assert_eq!(recovered.token.token, None);
+    assert_eq!(recovered.stream, "c;\n");
+    assert_eq!(
+      recovered.token.errors[0].last(),
+      &FromAtom::Base(BaseAtom::Is {
+        t: Some('b'),
+        expect: ';',
+      })
+    );
+
+    let parsed = entry.recover_with(skip_until(sync)).parse("a;\n");
+    assert_eq!(parsed.unwrap().token.token, Some(Entry::Key('a')));
+  }
+
+  #[test]
+  fn fold_bounds_recovering() {
+    let parsed = entry
+      .fold_bounds_recovering(.., skip_until(sync), Vec::new, |acc, entry| {
+        acc.acc(entry.unwrap_or(Entry::Invalid))
+      })
+      .parse("a;\nbc;\nd;\nef\n");
+    let recovered = parsed.unwrap();
+    assert_eq!(
+      recovered.token.token,
+      [
+        Entry::Key('a'),
+        Entry::Invalid,
+        Entry::Key('d'),
+        Entry::Invalid
+      ]
+    );
+    assert_eq!(recovered.token.errors.len(), 2);
+    assert_eq!(recovered.stream, "");
+  }
+}
@@ -0,0 +1,174 @@
+use alloc::{
+  boxed::Box,
+  rc::Rc,
+};
+
+use crate::{
+  utils::UtilsAtom,
+  Contexting,
+  Parse,
+  Parsed,
+};
+
+type Boxed<'a, Stream, Context, Token> = Box<dyn Parse<Stream, Context, Token = Token> + 'a>;
+type Builder<'a, Stream, Context, Token> =
+  dyn Fn(Recursive<'a, Stream, Context, Token>) -> Boxed<'a, Stream, Context, Token> + 'a;
+
+/// Implementation of [recursive], it's also the parser given to the closure
+/// to refer to itself. The parser is built when it's used for the first time
+/// at a depth so a recursive grammar only build what the input need. Clone
+/// doesn't clone the parser already built.
+pub struct Recursive<'a, Stream, Context, Token> {
+  builder: Rc<Builder<'a, Stream, Context, Token>>,
+  parser: Option<Boxed<'a, Stream, Context, Token>>,
+  depth: usize,
+  max: Option<usize>,
+}
+
+impl<'a, Stream, Context, Token> Clone for Recursive<'a, Stream, Context, Token> {
+  fn clone(&self) -> Self {
+    Self {
+      builder: Rc::clone(&self.builder),
+      parser: None,
+      depth: self.depth,
+      max: self.max,
+    }
+  }
+}
+
+impl<'a, Stream, Context, Token> Recursive<'a, Stream, Context, Token> {
+  /// Limit the number of time the parser can refer to itself, a reference
+  /// over the limit return an Error with [UtilsAtom::MaxDepth] instead of
+  /// overflow the stack, so the limit reach the caller and alternatives are
+  /// not tried. A reference count as soon as it's tried, even if it would
+  /// fail. Must be called before the first use.
+  pub const fn max_depth(mut self, max: usize) -> Self {
+    self.max = Some(max);
+    self
+  }
+}
+
+impl<'a, Stream, Context, Token> Parse<Stream, Context> for Recursive<'a, Stream, Context, Token>
+where
+  Context: Contexting<UtilsAtom<Stream>>,
+{
+  type Token = Token;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    if let Some(max) = self.max.filter(|&max| self.depth > max) {
+      return Parsed::Error(Context::new(UtilsAtom::MaxDepth(max)));
+    }
+
+    let (builder, depth, max) = (&self.builder, self.depth, self.max);
+    self
+      .parser
+      .get_or_insert_with(|| {
+        builder(Recursive {
+          builder: Rc::clone(builder),
+          parser: None,
+          depth: depth + 1,
+          max,
+        })
+      })
+      .parse(stream)
+  }
+}
+
+/// Return a parser that can refer to itself, f receive the parser and must
+/// return the parser to use. This allow to write recursive grammar with
+/// closure, for example nested parentheses:
+/// `recursive(|this| is('(').drop_and(this.opt()).and_drop(is(')')))`.
+pub fn recursive<'a, Stream, Context, Token, Parser, F>(
+  f: F,
+) -> Recursive<'a, Stream, Context, Token>
+where
+  Context: Contexting<UtilsAtom<Stream>>,
+  Parser: Parse<Stream, Context, Token = Token> + 'a,
+  F: Fn(Recursive<'a, Stream, Context, Token>) -> Parser + 'a,
+{
+  Recursive {
+    builder: Rc::new(move |this| Box::new(f(this))),
+    parser: None,
+    depth: 0,
+    max: None,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use super::recursive;
+  use crate::{
+    base::{
+      is,
+      BaseAtom,
+    },
+    context::{
+      Keep,
+      Last,
+    },
+    utils::{
+      Utils,
+      UtilsAtom,
+    },
+    CoreAtom,
+    Parse,
+    Parsed,
+    ProvideElement,
+    Success,
+  };
+
+  type Stream = &'static str;
+
+  #[derive(Display, Debug, From, PartialEq)]
+  enum FromAtom {
+    Utils(UtilsAtom<Stream>),
+    Base(BaseAtom<char>),
+    Core(CoreAtom<Stream>),
+  }
+
+  type HandleAtom = Keep<Last, FromAtom>;
+
+  // count the number of nested parentheses
+  fn nested(max: usize, stream: Stream) -> Parsed<usize, Stream, HandleAtom> {
+    recursive(|this| {
+      is('(')
+        .drop_and(this.opt())
+        .and_drop(is(')'))
+        .map(|inner: Option<usize>| inner.map_or(1, |depth| depth + 1))
+    })
+    .max_depth(max)
+    .parse(stream)
+  }
+
+  #[test]
+  fn nested_parentheses() {
+    let Success { token, stream } = nested(usize::MAX, "((()))!").unwrap();
+    assert_eq!(token, 3);
+    assert_eq!(stream, "!");
+
+    assert!(matches!(nested(usize::MAX, "(()"), Parsed::Failure(_)));
+  }
+
+  #[test]
+  fn max_depth() {
+    // the parser try to refer to itself exactly 2 times
+    let Success { token, stream } = nested(2, "(())!").unwrap();
+    assert_eq!(token, 2);
+    assert_eq!(stream, "!");
+
+    // the third reference is an Error, opt doesn't try without it
+    let Parsed::Error(context) = nested(2, "((()))") else {
+      panic!()
+    };
+    assert_eq!(context.last(), &FromAtom::Utils(UtilsAtom::MaxDepth(2)));
+    let Parsed::Error(context) = nested(1, "(())") else {
+      panic!()
+    };
+    assert_eq!(context.last(), &FromAtom::Utils(UtilsAtom::MaxDepth(1)));
+  }
+}
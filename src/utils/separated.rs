@@ -0,0 +1,289 @@
+use core::fmt::Debug;
+
+use crate::{
+  utils::{
+    FoldBoundsParse,
+    UtilsAtom,
+  },
+  Contexting,
+  Parse,
+  Parsed,
+  Streaming,
+  Success,
+};
+
+/// Policy about a separator that is not followed by an item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trailing {
+  /// The separator is left in the stream
+  Leave,
+  /// The separator is consumed if there was at least one item
+  Allow,
+  /// The separator is an Error, so alternatives are not tried
+  Deny,
+}
+
+// call sep before all item except the first, only live during one parse
+struct Item<'a, Parser, Sep, Stream> {
+  parser: &'a mut Parser,
+  sep: &'a mut Sep,
+  trailing: Trailing,
+  first: bool,
+  // stream after a separator not followed by an item
+  trailing_sep: Option<Stream>,
+}
+
+impl<'a, Stream, Context, Parser, Sep> Parse<Stream, Context> for Item<'a, Parser, Sep, Stream>
+where
+  Stream: Streaming,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Parser: Parse<Stream, Context>,
+  Sep: Parse<Stream, Context>,
+{
+  type Token = Parser::Token;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    if self.first {
+      let parsed = self.parser.parse(stream);
+      self.first = !parsed.is_success();
+      return parsed;
+    }
+
+    let Success { token: _, stream } = self.sep.parse(stream)?;
+    match self.parser.parse(stream.clone()) {
+      Parsed::Failure(context) => match self.trailing {
+        Trailing::Leave => Parsed::Failure(context),
+        Trailing::Allow => {
+          self.trailing_sep = Some(stream);
+          Parsed::Failure(context)
+        }
+        Trailing::Deny => Parsed::Error(context.add(UtilsAtom::TrailingSeparator)),
+      },
+      parsed => parsed,
+    }
+  }
+}
+
+/// Implementation of [crate::utils::Utils::separated]
+#[derive(Clone)]
+pub struct Separated<Parser, Sep, Bounds, Init, F> {
+  parser: Parser,
+  sep: Sep,
+  trailing: Trailing,
+  bounds: Bounds,
+  init: Init,
+  f: F,
+}
+
+impl<Parser, Sep, Bounds, Init, F> Separated<Parser, Sep, Bounds, Init, F> {
+  /// Change the policy about separator not followed by an item
+  pub const fn trailing(mut self, trailing: Trailing) -> Self {
+    self.trailing = trailing;
+    self
+  }
+}
+
+impl<Bounds, Stream, Context, Parser, Sep, Acc, Init, F> Parse<Stream, Context>
+  for Separated<Parser, Sep, Bounds, Init, F>
+where
+  Stream: Streaming,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Parser: Parse<Stream, Context>,
+  Sep: Parse<Stream, Context>,
+  Init: FnMut() -> Acc,
+  F: FnMut(Acc, Parser::Token) -> Acc,
+  Bounds: FoldBoundsParse,
+  Acc: Debug,
+{
+  type Token = Acc;
+
+  fn parse(&mut self, stream: Stream) -> Parsed<Acc, Stream, Context> {
+    let mut item = Item {
+      parser: &mut self.parser,
+      sep: &mut self.sep,
+      trailing: self.trailing,
+      first: true,
+      trailing_sep: None,
+    };
+    let Success { token, stream } =
+      self
+        .bounds
+        .fold_bounds(&mut item, &mut self.init, &mut self.f, stream)?;
+
+    // when max is reached the next separator is not a trailing one
+    Parsed::Success {
+      token,
+      stream: item.trailing_sep.unwrap_or(stream),
+    }
+  }
+}
+
+/// Function style version of [crate::utils::Utils::separated]
+pub const fn separated<Bounds, Stream, Context, Parser, Sep, Acc, Init, F>(
+  parser: Parser, sep: Sep, bounds: Bounds, init: Init, fold: F,
+) -> Separated<Parser, Sep, Bounds, Init, F>
+where
+  Stream: Streaming,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Parser: Parse<Stream, Context>,
+  Sep: Parse<Stream, Context>,
+  Init: FnMut() -> Acc,
+  F: FnMut(Acc, Parser::Token) -> Acc,
+  Bounds: FoldBoundsParse,
+  Acc: Debug,
+{
+  Separated {
+    parser,
+    sep,
+    trailing: Trailing::Leave,
+    bounds,
+    init,
+    f: fold,
+  }
+}
+
+/// Function style version of [crate::utils::Utils::separated_trailing]
+pub const fn separated_trailing<Bounds, Stream, Context, Parser, Sep, Acc, Init, F>(
+  parser: Parser, sep: Sep, bounds: Bounds, init: Init, fold: F,
+) -> Separated<Parser, Sep, Bounds, Init, F>
+where
+  Stream: Streaming,
+  Context: Contexting<UtilsAtom<Stream>>,
+  Parser: Parse<Stream, Context>,
+  Sep: Parse<Stream, Context>,
+  Init: FnMut() -> Acc,
+  F: FnMut(Acc, Parser::Token) -> Acc,
+  Bounds: FoldBoundsParse,
+  Acc: Debug,
+{
+  separated(parser, sep, bounds, init, fold).trailing(Trailing::Allow)
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use derive_more::{
+    Display,
+    From,
+  };
+
+  use super::Trailing;
+  use crate::{
+    base::{
+      is,
+      octet,
+      BaseAtom,
+    },
+    context::{
+      Keep,
+      Last,
+    },
+    utils::{
+      Acc,
+      Utils,
+      UtilsAtom,
+    },
+    CoreAtom,
+    Parse,
+    Parsed,
+    ProvideElement,
+    Success,
+  };
+
+  type Stream = &'static [u8];
+
+  #[derive(Display, Debug, From, PartialEq)]
+  enum FromAtom {
+    Utils(UtilsAtom<Stream>),
+    Base(BaseAtom<u8>),
+    Core(CoreAtom<Stream>),
+  }
+
+  type HandleAtom = Keep<Last, FromAtom>;
+
+  fn list<Bounds>(
+    bounds: Bounds, trailing: Trailing, stream: Stream,
+  ) -> Parsed<Vec<u8>, Stream, HandleAtom>
+  where
+    Bounds: super::FoldBoundsParse,
+  {
+    octet
+      .filter(u8::is_ascii_digit)
+      .separated(is(b','), bounds, Vec::new, Acc::acc)
+      .trailing(trailing)
+      .parse(stream)
+  }
+
+  #[test]
+  fn separated() {
+    let Success { token, stream } = list(.., Trailing::Leave, b"1,2,3!").unwrap();
+    assert_eq!(token, b"123");
+    assert_eq!(stream, b"!");
+
+    let Success { token, stream } = list(.., Trailing::Leave, b"!").unwrap();
+    assert_eq!(token, b"");
+    assert_eq!(stream, b"!");
+
+    let Success { token, stream } = list(..2, Trailing::Leave, b"1,2,3").unwrap();
+    assert_eq!(token, b"12");
+    assert_eq!(stream, b",3");
+  }
+
+  #[test]
+  fn reuse() {
+    let mut parser = octet
+      .filter(u8::is_ascii_digit)
+      .separated(is(b','), .., Vec::new, Acc::acc);
+    for (stream, expected) in [(&b"1,2!"[..], &b"12"[..]), (b"!", b""), (b"3,4,5", b"345")] {
+      let parsed: Parsed<Vec<u8>, _, HandleAtom> = parser.parse(stream);
+      assert_eq!(parsed.unwrap().token, expected);
+    }
+  }
+
+  #[test]
+  fn min_not_reach() {
+    let Parsed::Failure(context) = list(3.., Trailing::Leave, b"1,2!") else {
+      panic!()
+    };
+    assert_eq!(
+      context.last(),
+      &FromAtom::Utils(UtilsAtom::MinNotReach { i: 2, min: 3 })
+    );
+  }
+
+  #[test]
+  fn trailing() {
+    let Success { token, stream } = list(.., Trailing::Leave, b"1,2,!").unwrap();
+    assert_eq!(token, b"12");
+    assert_eq!(stream, b",!");
+
+    let Success { token, stream } = list(.., Trailing::Allow, b"1,2,!").unwrap();
+    assert_eq!(token, b"12");
+    assert_eq!(stream, b"!");
+
+    let Success { stream, .. } = list(.., Trailing::Allow, b",!").unwrap();
+    assert_eq!(stream, b",!");
+
+    // the separator before an item over max is not trailing
+    let Success { token, stream } = list(..2, Trailing::Allow, b"1,2,3").unwrap();
+    assert_eq!(token, b"12");
+    assert_eq!(stream, b",3");
+
+    let Parsed::Error(context) = list(.., Trailing::Deny, b"1,2,!") else {
+      panic!()
+    };
+    assert_eq!(
+      context.last(),
+      &FromAtom::Utils(UtilsAtom::TrailingSeparator)
+    );
+
+    let parsed: Parsed<_, _, HandleAtom> = octet
+      .filter(u8::is_ascii_digit)
+      .separated_trailing(is(b';'), 1.., Vec::new, Acc::acc)
+      .parse(&b"1;2;"[..]);
+    let Success { token, stream } = parsed.unwrap();
+    assert_eq!(token, b"12");
+    assert_eq!(stream, b"");
+  }
+}
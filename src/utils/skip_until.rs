@@ -0,0 +1,52 @@
+use crate::{
+  Contexting,
+  CoreAtom,
+  Parse,
+  Parsed,
+  Split,
+  Streaming,
+};
+
+/// Implementation of [skip_until]
+#[derive(Clone)]
+pub struct SkipUntil<SyncParser> {
+  sync: SyncParser,
+}
+
+impl<Stream, Context, SyncParser> Parse<Stream, Context> for SkipUntil<SyncParser>
+where
+  Stream: Streaming,
+  SyncParser: Parse<Stream, Context>,
+  Context: Contexting<CoreAtom<Stream>>,
+{
+  type Token = SyncParser::Token;
+
+  fn parse(&mut self, mut stream: Stream) -> Parsed<Self::Token, Stream, Context> {
+    loop {
+      match self.sync.parse(stream.clone()) {
+        Parsed::Failure(_context) => {}
+        parsed => return parsed,
+      }
+      stream = match stream.split_first() {
+        Split::Success { item: _, stream } => stream,
+        Split::NotEnoughItem(stream) => {
+          return Parsed::Failure(Context::new(CoreAtom::EndOfStream { stream }));
+        }
+        Split::Error(error) => return Parsed::Error(Context::new(CoreAtom::Error { error })),
+      }
+    }
+  }
+}
+
+/// Skip items until sync parser is successful, sync parser is tried before
+/// each item. Return the Token of sync parser or a Failure if end of stream is
+/// reached. Mostly used to resynchronize the stream after a failure, see
+/// [crate::utils::Utils::recover_with].
+pub const fn skip_until<Stream, Context, SyncParser>(sync: SyncParser) -> SkipUntil<SyncParser>
+where
+  Stream: Streaming,
+  SyncParser: Parse<Stream, Context>,
+  Context: Contexting<CoreAtom<Stream>>,
+{
+  SkipUntil { sync }
+}